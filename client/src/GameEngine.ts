@@ -7,6 +7,12 @@ import { SceneManager, AnimationName, LocalPlayerRenderData, RemotePlayerRenderD
 // --- Constants ---
 const UPDATE_INTERVAL = 1000 / 30; // 30 times per second (ms)
 const ROTATION_THRESHOLD = 0.01; // Min rotation change to send update
+const PERSONAL_SPACE_RADII = [undefined, 1, 2, 3]; // Cycled with 'O', undefined turns the bubble off
+
+// The client measures pitch from straight down, the server from straight ahead (positive looks down)
+function toServerPitch(pitch: number): number {
+    return pitch - Math.PI / 2;
+}
 
 // --- Immutable Game State Definition ---
 interface LocalPlayerState {
@@ -17,6 +23,7 @@ interface LocalPlayerState {
   readonly input: InputState;
   readonly animationState: AnimationState;
   readonly hexColor?: string; // Player's hex color from database
+  readonly personalSpaceRadius?: number; // Remote players closer than this are faded out
 }
 
 interface GameState {
@@ -82,7 +89,7 @@ function updateRemotePlayersMap(
 
 // --- Initial State ---
 const initialInputState: InputState = {
-    forward: false, backward: false, left: false, right: false, jump: false, isPointerLocked: false
+    forward: false, backward: false, left: false, right: false, jump: false, crouch: false, isPointerLocked: false
 };
 
 const initialGameState: GameState = {
@@ -105,7 +112,7 @@ export class GameEngine {
   // Connection related
   private connection: moduleBindings.DbConnection | null = null;
   private lastUpdateTime: number = 0; // For throttling server updates
-  private lastSentInput: InputState & { rotation: number };
+  private lastSentInput: InputState & { rotation: number, pitch: number };
   private dbCallbacks: { onInsert: any, onDelete: any, onUpdate: any } | null = null;
 
   private isDisposed: boolean = false;
@@ -117,7 +124,7 @@ export class GameEngine {
 
     // Basic State Init (Immutable)
     this.state = initialGameState;
-    this.lastSentInput = { ...initialInputState, rotation: 0 };
+    this.lastSentInput = { ...initialInputState, rotation: 0, pitch: 0 };
 
     // Initialize Input Manager
     this.inputManager = new InputManager(this.canvasContainer, this.handleInputUpdate.bind(this));
@@ -150,8 +157,24 @@ export class GameEngine {
     if (e.key.toLowerCase() === ']') {
       this.sceneManager.setPreviousVisibleCollisionMesh();
     }
+    if (e.key.toLowerCase() === 'o') {
+      this.cyclePersonalSpace();
+    }
   };
 
+  // Switch to the next personal space radius, the server stores it on the player
+  private cyclePersonalSpace(): void {
+    if (!this.connection || !this.state.localPlayer) return;
+    const current = PERSONAL_SPACE_RADII.indexOf(this.state.localPlayer.personalSpaceRadius);
+    const next = PERSONAL_SPACE_RADII[(current + 1) % PERSONAL_SPACE_RADII.length];
+    try {
+      this.connection.reducers.setPersonalSpace(next);
+      console.log("Personal space radius:", next ?? "off");
+    } catch (error) {
+      console.error("Error setting personal space:", error);
+    }
+  }
+
   // Callback function passed to InputManager to handle state updates
   private handleInputUpdate(update: Parameters<InputUpdateCallback>[0]): void {
     if (!this.state.localPlayer) return;
@@ -248,8 +271,12 @@ export class GameEngine {
       });
     });
     
-    // Update remote players
-    this.sceneManager.updateRemotePlayers(remotePlayersData);
+    // Update remote players, fading the ones inside the local player's personal space
+    const radius = state.localPlayer?.personalSpaceRadius;
+    const personalSpace = state.localPlayer && radius !== undefined
+      ? { center: state.localPlayer.position, radius }
+      : null;
+    this.sceneManager.updateRemotePlayers(remotePlayersData, personalSpace);
   }
   
   // Convert local player state to render data for SceneManager
@@ -318,19 +345,22 @@ export class GameEngine {
     if (!this.connection || !this.state.localPlayer) return;
 
     const now = performance.now();
-    const { input, rotationY } = this.state.localPlayer;
+    const { input, rotationY, pitch: clientPitch } = this.state.localPlayer;
     const currentRotation = rotationY + Math.PI; // Server expects rotation relative to +Z
+    const currentPitch = toServerPitch(clientPitch);
 
     // Check thresholds against last *sent* data
-    const { rotation, ...lastSentInput } = this.lastSentInput;
+    const { rotation, pitch, ...lastSentInput } = this.lastSentInput;
     const inputChanged = JSON.stringify(input) !== JSON.stringify(lastSentInput);
-    const rotationChanged = Math.abs(currentRotation - rotation) > ROTATION_THRESHOLD;
+    const rotationChanged = Math.abs(currentRotation - rotation) > ROTATION_THRESHOLD
+        || Math.abs(currentPitch - pitch) > ROTATION_THRESHOLD;
 
     // Send position/rotation update if interval passed AND values changed
     if (now - this.lastUpdateTime >= UPDATE_INTERVAL && (inputChanged || rotationChanged)) {
         try {
-            this.connection.reducers.updatePlayerInput(input, currentRotation);
-            this.lastSentInput = { ...input, rotation: currentRotation };
+            // The client has no head roll, only VR headsets would send one
+            this.connection.reducers.updatePlayerInput(input, currentRotation, currentPitch, 0);
+            this.lastSentInput = { ...input, rotation: currentRotation, pitch: currentPitch };
             this.lastUpdateTime = now;
         } catch (error) {
             console.error("Failed to send player position update:", error);
@@ -363,19 +393,20 @@ export class GameEngine {
 
     // Calculate initial values to send to the server
     const initialServerRotation = initialRotationY + Math.PI; // Server expects rotation relative to +Z
+    const initialServerPitch = toServerPitch(initialPitch);
 
     // Send initial state immediately
     if (this.connection && this.state.localPlayer) {
       try {
-        this.connection.reducers.updatePlayerInput(initialInputState, initialServerRotation);
-        this.lastSentInput = { ...initialInputState, rotation: initialServerRotation };
+        this.connection.reducers.updatePlayerInput(initialInputState, initialServerRotation, initialServerPitch, 0);
+        this.lastSentInput = { ...initialInputState, rotation: initialServerRotation, pitch: initialServerPitch };
 
         this.lastUpdateTime = performance.now();
       } catch (error) {
         console.error("Failed to send initial player state on connect:", error);
       }
     } else {
-        this.lastSentInput = { ...initialInputState, rotation: initialServerRotation };
+        this.lastSentInput = { ...initialInputState, rotation: initialServerRotation, pitch: initialServerPitch };
         this.lastUpdateTime = 0;
     }
 
@@ -421,7 +452,8 @@ export class GameEngine {
        if (playerId === localPlayerIdentity && this.state.localPlayer) {
          const updatedLocalPlayer = {
            ...this.state.localPlayer,
-           hexColor: player.hexColor
+           hexColor: player.hexColor,
+           personalSpaceRadius: player.personalSpaceRadius
          };
          
          this.state = {
@@ -463,6 +495,7 @@ export class GameEngine {
             localPlayer: {
               ...this.state.localPlayer,
               hexColor: newPlayer.hexColor,
+              personalSpaceRadius: newPlayer.personalSpaceRadius,
               position: new THREE.Vector3(newPlayer.position.x, newPlayer.position.y, newPlayer.position.z),
               animationState: newPlayer.animationState as AnimationState,
             }
@@ -511,7 +544,8 @@ export class GameEngine {
             ...this.state,
             localPlayer: {
               ...this.state.localPlayer,
-              hexColor: localPlayerData.hexColor
+              hexColor: localPlayerData.hexColor,
+              personalSpaceRadius: localPlayerData.personalSpaceRadius
             }
           };
         }
@@ -13,6 +13,7 @@ export interface InputState {
   readonly left: boolean;
   readonly right: boolean;
   readonly jump: boolean;
+  readonly crouch: boolean; // Moves down while swimming, climbing or flying
   readonly isPointerLocked: boolean;
 }
 
@@ -24,7 +25,7 @@ interface MouseMovement {
 // Type for the callback function to update GameEngine state
 export type InputUpdateCallback = (
     update: |
-    { type: 'key', key: 'forward' | 'backward' | 'left' | 'right' | 'jump', pressed: boolean } |
+    { type: 'key', key: 'forward' | 'backward' | 'left' | 'right' | 'jump' | 'crouch', pressed: boolean } |
     { type: 'rotation', deltaX: number, deltaY: number } |
     { type: 'pointerLock', isLocked: boolean }
 ) => void;
@@ -74,6 +75,7 @@ export class InputManager {
             case 'a': this.updateCallback({ type: 'key', key: 'left', pressed: true }); break;
             case 'd': this.updateCallback({ type: 'key', key: 'right', pressed: true }); break;
             case ' ': this.updateCallback({ type: 'key', key: 'jump', pressed: true }); break;
+            case 'c': this.updateCallback({ type: 'key', key: 'crouch', pressed: true }); break;
         }
     };
 
@@ -85,6 +87,7 @@ export class InputManager {
             case 'a': this.updateCallback({ type: 'key', key: 'left', pressed: false }); break;
             case 'd': this.updateCallback({ type: 'key', key: 'right', pressed: false }); break;
             case ' ': this.updateCallback({ type: 'key', key: 'jump', pressed: false }); break;
+            case 'c': this.updateCallback({ type: 'key', key: 'crouch', pressed: false }); break;
         }
     };
 
@@ -8,6 +8,7 @@ import * as moduleBindings from './module_bindings/index';
 const ANIMATION_FADE_DURATION = 0.2; // seconds
 const CAMERA_DISTANCE = 2; // Distance behind the character
 const CAMERA_HEIGHT = 1; // Height above the character
+const PERSONAL_SPACE_OPACITY = 0.2; // Opacity of remote players inside the personal space bubble
 
 // --- Type Definitions ---
 export type AnimationName = 'idle' | 'walk';
@@ -44,6 +45,12 @@ export interface RemotePlayerRenderData {
     readonly hexColor?: string; // Optional hex color for player customization
 }
 
+// Remote players within `radius` of `center` are faded out
+export interface PersonalSpace {
+    readonly center: THREE.Vector3;
+    readonly radius: number;
+}
+
 // Callback for when all assets are loaded
 export type AssetsLoadedCallback = () => void;
 
@@ -299,6 +306,24 @@ export class SceneManager {
         };
     }
     
+    // Fade a player model in or out, its materials are cloned per model so other players are unaffected
+    private setModelOpacity(model: THREE.Group, opacity: number): void {
+        if (model.userData.opacity === opacity) return;
+        model.userData.opacity = opacity;
+
+        model.traverse((node) => {
+            // Debug markers keep their own opacity
+            if (node instanceof THREE.Mesh && node.material && !node.name.startsWith('debug_marker')) {
+                const materials = Array.isArray(node.material) ? node.material : [node.material];
+                materials.forEach(mat => {
+                    mat.transparent = opacity < 1;
+                    mat.opacity = opacity;
+                    mat.depthWrite = opacity >= 1;
+                });
+            }
+        });
+    }
+
     // Helper method to clone materials for a model to ensure unique instances
     private cloneMaterials(model: THREE.Group): void {
         model.traverse((node) => {
@@ -553,7 +578,7 @@ export class SceneManager {
     }
     
     // Update remote players in the scene based on render data
-    public updateRemotePlayers(players: Map<string, RemotePlayerRenderData>): void {
+    public updateRemotePlayers(players: Map<string, RemotePlayerRenderData>, personalSpace: PersonalSpace | null = null): void {
         // Track existing IDs to identify players to remove
         const existingIds = new Set(this.playerRenderComponents.keys());
         
@@ -630,6 +655,11 @@ export class SceneManager {
                     }
                 }
                 
+                // Ghost players that are too close to the local player
+                const inPersonalSpace = personalSpace !== null
+                    && renderComponent.model.position.distanceTo(personalSpace.center) < personalSpace.radius;
+                this.setModelOpacity(renderComponent.model, inPersonalSpace ? PERSONAL_SPACE_OPACITY : 1);
+
                 // Update tracking position
                 renderComponent.lastPositionSq.set(playerData.position.x, playerData.position.y);
             }
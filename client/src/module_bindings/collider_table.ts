@@ -32,6 +32,8 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 import { Collider } from "./collider_type";
 import { DbVector3 as __DbVector3 } from "./db_vector_3_type";
+import { SurfaceMaterial as __SurfaceMaterial } from "./surface_material_type";
+import { VolumeType as __VolumeType } from "./volume_type_type";
 
 import { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
 
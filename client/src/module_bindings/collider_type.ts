@@ -31,12 +31,17 @@ import {
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
 import { DbVector3 as __DbVector3 } from "./db_vector_3_type";
+import { SurfaceMaterial as __SurfaceMaterial } from "./surface_material_type";
+import { VolumeType as __VolumeType } from "./volume_type_type";
 
 export type Collider = {
   id: number,
   positions: __DbVector3[],
   indices: __DbVector3[],
   name: string,
+  sceneVersion: number,
+  material: __SurfaceMaterial,
+  volume: __VolumeType | undefined,
 };
 
 /**
@@ -53,6 +58,9 @@ export namespace Collider {
       new ProductTypeElement("positions", AlgebraicType.createArrayType(__DbVector3.getTypeScriptAlgebraicType())),
       new ProductTypeElement("indices", AlgebraicType.createArrayType(__DbVector3.getTypeScriptAlgebraicType())),
       new ProductTypeElement("name", AlgebraicType.createStringType()),
+      new ProductTypeElement("sceneVersion", AlgebraicType.createU32Type()),
+      new ProductTypeElement("material", __SurfaceMaterial.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("volume", AlgebraicType.createOptionType(__VolumeType.getTypeScriptAlgebraicType())),
     ]);
   }
 
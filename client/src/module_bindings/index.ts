@@ -32,79 +32,707 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 
 // Import and reexport all reducer arg types
+import { AcceptFriendRequest } from "./accept_friend_request_reducer.ts";
+export { AcceptFriendRequest };
+import { BanPlayer } from "./ban_player_reducer.ts";
+export { BanPlayer };
+import { BlockUser } from "./block_user_reducer.ts";
+export { BlockUser };
+import { CheckIdlePlayers } from "./check_idle_players_reducer.ts";
+export { CheckIdlePlayers };
+import { ClearInputRecording } from "./clear_input_recording_reducer.ts";
+export { ClearInputRecording };
+import { ClearTrackedPose } from "./clear_tracked_pose_reducer.ts";
+export { ClearTrackedPose };
 import { Connect } from "./connect_reducer.ts";
 export { Connect };
+import { CreatePlatform } from "./create_platform_reducer.ts";
+export { CreatePlatform };
+import { CreatePortal } from "./create_portal_reducer.ts";
+export { CreatePortal };
+import { CreateRoom } from "./create_room_reducer.ts";
+export { CreateRoom };
+import { CreateSeat } from "./create_seat_reducer.ts";
+export { CreateSeat };
+import { DeleteRoom } from "./delete_room_reducer.ts";
+export { DeleteRoom };
 import { Disconnect } from "./disconnect_reducer.ts";
 export { Disconnect };
+import { GetFriendPresence } from "./get_friend_presence_reducer.ts";
+export { GetFriendPresence };
+import { GetLeaderboard } from "./get_leaderboard_reducer.ts";
+export { GetLeaderboard };
+import { GrantAdmin } from "./grant_admin_reducer.ts";
+export { GrantAdmin };
+import { InviteToInstance } from "./invite_to_instance_reducer.ts";
+export { InviteToInstance };
+import { JoinFriend } from "./join_friend_reducer.ts";
+export { JoinFriend };
+import { JoinInstance } from "./join_instance_reducer.ts";
+export { JoinInstance };
+import { Kick } from "./kick_reducer.ts";
+export { Kick };
+import { MutePlayer } from "./mute_player_reducer.ts";
+export { MutePlayer };
+import { PlayEmote } from "./play_emote_reducer.ts";
+export { PlayEmote };
+import { PointQuery } from "./point_query_reducer.ts";
+export { PointQuery };
+import { PurgeLoggedOutPlayers } from "./purge_logged_out_players_reducer.ts";
+export { PurgeLoggedOutPlayers };
+import { Raycast } from "./raycast_reducer.ts";
+export { Raycast };
+import { RemoveEmote } from "./remove_emote_reducer.ts";
+export { RemoveEmote };
+import { RemoveExpiredMutes } from "./remove_expired_mutes_reducer.ts";
+export { RemoveExpiredMutes };
+import { RemoveFriend } from "./remove_friend_reducer.ts";
+export { RemoveFriend };
+import { RemovePlatform } from "./remove_platform_reducer.ts";
+export { RemovePlatform };
+import { RemovePortal } from "./remove_portal_reducer.ts";
+export { RemovePortal };
+import { RemoveSeat } from "./remove_seat_reducer.ts";
+export { RemoveSeat };
+import { RevokeAdmin } from "./revoke_admin_reducer.ts";
+export { RevokeAdmin };
+import { RevokeInvite } from "./revoke_invite_reducer.ts";
+export { RevokeInvite };
+import { SendFriendRequest } from "./send_friend_request_reducer.ts";
+export { SendFriendRequest };
+import { SetColliderMaterial } from "./set_collider_material_reducer.ts";
+export { SetColliderMaterial };
+import { SetColliderVolume } from "./set_collider_volume_reducer.ts";
+export { SetColliderVolume };
+import { SetDefaultScene } from "./set_default_scene_reducer.ts";
+export { SetDefaultScene };
+import { SetEmote } from "./set_emote_reducer.ts";
+export { SetEmote };
+import { SetIdleTimeouts } from "./set_idle_timeouts_reducer.ts";
+export { SetIdleTimeouts };
+import { SetInputRecording } from "./set_input_recording_reducer.ts";
+export { SetInputRecording };
+import { SetInstanceVisibility } from "./set_instance_visibility_reducer.ts";
+export { SetInstanceVisibility };
+import { SetLoggedOutRetention } from "./set_logged_out_retention_reducer.ts";
+export { SetLoggedOutRetention };
+import { SetPersistentProfile } from "./set_persistent_profile_reducer.ts";
+export { SetPersistentProfile };
+import { SetPersonalSpace } from "./set_personal_space_reducer.ts";
+export { SetPersonalSpace };
+import { SetPlayerCollisions } from "./set_player_collisions_reducer.ts";
+export { SetPlayerCollisions };
+import { SetPlayerMovementMode } from "./set_player_movement_mode_reducer.ts";
+export { SetPlayerMovementMode };
+import { SetSceneSpawnPoint } from "./set_scene_spawn_point_reducer.ts";
+export { SetSceneSpawnPoint };
+import { SetWorldPlayerCollisions } from "./set_world_player_collisions_reducer.ts";
+export { SetWorldPlayerCollisions };
+import { ShapeCast } from "./shape_cast_reducer.ts";
+export { ShapeCast };
+import { Sit } from "./sit_reducer.ts";
+export { Sit };
+import { SnapshotPhysics } from "./snapshot_physics_reducer.ts";
+export { SnapshotPhysics };
+import { Stand } from "./stand_reducer.ts";
+export { Stand };
 import { Tick } from "./tick_reducer.ts";
 export { Tick };
+import { UnbanPlayer } from "./unban_player_reducer.ts";
+export { UnbanPlayer };
+import { UnblockUser } from "./unblock_user_reducer.ts";
+export { UnblockUser };
+import { UnmutePlayer } from "./unmute_player_reducer.ts";
+export { UnmutePlayer };
 import { UpdatePlayerInput } from "./update_player_input_reducer.ts";
 export { UpdatePlayerInput };
+import { UpdateTrackedPose } from "./update_tracked_pose_reducer.ts";
+export { UpdateTrackedPose };
 import { UploadBody } from "./upload_body_reducer.ts";
 export { UploadBody };
 
 // Import and reexport all table handle types
+import { AdminTableHandle } from "./admin_table.ts";
+export { AdminTableHandle };
+import { AuditLogTableHandle } from "./audit_log_table.ts";
+export { AuditLogTableHandle };
+import { BanTableHandle } from "./ban_table.ts";
+export { BanTableHandle };
+import { BlockTableHandle } from "./block_table.ts";
+export { BlockTableHandle };
+import { CleanupScheduleTableHandle } from "./cleanup_schedule_table.ts";
+export { CleanupScheduleTableHandle };
+import { ClientConnectionTableHandle } from "./client_connection_table.ts";
+export { ClientConnectionTableHandle };
 import { ColliderTableHandle } from "./collider_table.ts";
 export { ColliderTableHandle };
+import { EmoteCatalogTableHandle } from "./emote_catalog_table.ts";
+export { EmoteCatalogTableHandle };
+import { FriendPresenceResultTableHandle } from "./friend_presence_result_table.ts";
+export { FriendPresenceResultTableHandle };
+import { FriendshipTableHandle } from "./friendship_table.ts";
+export { FriendshipTableHandle };
+import { IdleCheckScheduleTableHandle } from "./idle_check_schedule_table.ts";
+export { IdleCheckScheduleTableHandle };
+import { InputRateLimitTableHandle } from "./input_rate_limit_table.ts";
+export { InputRateLimitTableHandle };
+import { InstanceTableHandle } from "./instance_table.ts";
+export { InstanceTableHandle };
+import { InstanceInviteTableHandle } from "./instance_invite_table.ts";
+export { InstanceInviteTableHandle };
+import { LeaderboardResultTableHandle } from "./leaderboard_result_table.ts";
+export { LeaderboardResultTableHandle };
 import { LoggedOutPlayerTableHandle } from "./logged_out_player_table.ts";
 export { LoggedOutPlayerTableHandle };
+import { ModerationFlagTableHandle } from "./moderation_flag_table.ts";
+export { ModerationFlagTableHandle };
+import { MuteTableHandle } from "./mute_table.ts";
+export { MuteTableHandle };
+import { MuteExpiryScheduleTableHandle } from "./mute_expiry_schedule_table.ts";
+export { MuteExpiryScheduleTableHandle };
+import { PhysicsSnapshotTableHandle } from "./physics_snapshot_table.ts";
+export { PhysicsSnapshotTableHandle };
+import { PlatformTableHandle } from "./platform_table.ts";
+export { PlatformTableHandle };
 import { PlayerTableHandle } from "./player_table.ts";
 export { PlayerTableHandle };
+import { PlayerStatsTableHandle } from "./player_stats_table.ts";
+export { PlayerStatsTableHandle };
+import { PortalTableHandle } from "./portal_table.ts";
+export { PortalTableHandle };
+import { PortalCooldownTableHandle } from "./portal_cooldown_table.ts";
+export { PortalCooldownTableHandle };
+import { QueryResultTableHandle } from "./query_result_table.ts";
+export { QueryResultTableHandle };
+import { RecordedInputTableHandle } from "./recorded_input_table.ts";
+export { RecordedInputTableHandle };
+import { SceneSpawnTableHandle } from "./scene_spawn_table.ts";
+export { SceneSpawnTableHandle };
+import { SeatTableHandle } from "./seat_table.ts";
+export { SeatTableHandle };
+import { SessionTableHandle } from "./session_table.ts";
+export { SessionTableHandle };
+import { SimulationClockTableHandle } from "./simulation_clock_table.ts";
+export { SimulationClockTableHandle };
 import { TickScheduleTableHandle } from "./tick_schedule_table.ts";
 export { TickScheduleTableHandle };
+import { TrackedPoseTableHandle } from "./tracked_pose_table.ts";
+export { TrackedPoseTableHandle };
+import { WorldSettingsTableHandle } from "./world_settings_table.ts";
+export { WorldSettingsTableHandle };
 
 // Import and reexport all types
+import { Admin } from "./admin_type.ts";
+export { Admin };
+import { AuditAction } from "./audit_action_type.ts";
+export { AuditAction };
+import { AuditLog } from "./audit_log_type.ts";
+export { AuditLog };
+import { Ban } from "./ban_type.ts";
+export { Ban };
+import { Block } from "./block_type.ts";
+export { Block };
+import { CleanupSchedule } from "./cleanup_schedule_type.ts";
+export { CleanupSchedule };
+import { ClientConnection } from "./client_connection_type.ts";
+export { ClientConnection };
 import { Collider } from "./collider_type.ts";
 export { Collider };
+import { DbQuaternion } from "./db_quaternion_type.ts";
+export { DbQuaternion };
 import { DbVector3 } from "./db_vector_3_type.ts";
 export { DbVector3 };
+import { Emote } from "./emote_type.ts";
+export { Emote };
+import { FlagKind } from "./flag_kind_type.ts";
+export { FlagKind };
+import { FriendPresence } from "./friend_presence_type.ts";
+export { FriendPresence };
+import { FriendPresenceResult } from "./friend_presence_result_type.ts";
+export { FriendPresenceResult };
+import { Friendship } from "./friendship_type.ts";
+export { Friendship };
+import { FriendshipStatus } from "./friendship_status_type.ts";
+export { FriendshipStatus };
+import { IdleCheckSchedule } from "./idle_check_schedule_type.ts";
+export { IdleCheckSchedule };
+import { InputRateLimit } from "./input_rate_limit_type.ts";
+export { InputRateLimit };
 import { InputState } from "./input_state_type.ts";
 export { InputState };
+import { Instance } from "./instance_type.ts";
+export { Instance };
+import { InstanceInvite } from "./instance_invite_type.ts";
+export { InstanceInvite };
+import { InstanceVisibility } from "./instance_visibility_type.ts";
+export { InstanceVisibility };
+import { LeaderboardEntry } from "./leaderboard_entry_type.ts";
+export { LeaderboardEntry };
+import { LeaderboardMetric } from "./leaderboard_metric_type.ts";
+export { LeaderboardMetric };
+import { LeaderboardResult } from "./leaderboard_result_type.ts";
+export { LeaderboardResult };
+import { ModerationFlag } from "./moderation_flag_type.ts";
+export { ModerationFlag };
+import { MovementMode } from "./movement_mode_type.ts";
+export { MovementMode };
+import { Mute } from "./mute_type.ts";
+export { Mute };
+import { MuteExpirySchedule } from "./mute_expiry_schedule_type.ts";
+export { MuteExpirySchedule };
+import { PhysicsSnapshot } from "./physics_snapshot_type.ts";
+export { PhysicsSnapshot };
+import { Platform } from "./platform_type.ts";
+export { Platform };
+import { PlatformKeyframe } from "./platform_keyframe_type.ts";
+export { PlatformKeyframe };
 import { Player } from "./player_type.ts";
 export { Player };
+import { PlayerStats } from "./player_stats_type.ts";
+export { PlayerStats };
+import { Portal } from "./portal_type.ts";
+export { Portal };
+import { PortalCooldown } from "./portal_cooldown_type.ts";
+export { PortalCooldown };
+import { PresenceStatus } from "./presence_status_type.ts";
+export { PresenceStatus };
+import { QuantizedPose } from "./quantized_pose_type.ts";
+export { QuantizedPose };
+import { QueryKind } from "./query_kind_type.ts";
+export { QueryKind };
+import { QueryResult } from "./query_result_type.ts";
+export { QueryResult };
+import { RecordedInput } from "./recorded_input_type.ts";
+export { RecordedInput };
+import { SceneHit } from "./scene_hit_type.ts";
+export { SceneHit };
+import { SceneSpawn } from "./scene_spawn_type.ts";
+export { SceneSpawn };
+import { Seat } from "./seat_type.ts";
+export { Seat };
+import { Session } from "./session_type.ts";
+export { Session };
+import { SimulationClock } from "./simulation_clock_type.ts";
+export { SimulationClock };
+import { SurfaceMaterial } from "./surface_material_type.ts";
+export { SurfaceMaterial };
+import { SurfaceType } from "./surface_type_type.ts";
+export { SurfaceType };
 import { TickSchedule } from "./tick_schedule_type.ts";
 export { TickSchedule };
+import { TrackedPose } from "./tracked_pose_type.ts";
+export { TrackedPose };
+import { VolumeType } from "./volume_type_type.ts";
+export { VolumeType };
+import { WorldSettings } from "./world_settings_type.ts";
+export { WorldSettings };
 
 const REMOTE_MODULE = {
   tables: {
+    admin: {
+      tableName: "admin",
+      rowType: Admin.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
+    audit_log: {
+      tableName: "audit_log",
+      rowType: AuditLog.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    ban: {
+      tableName: "ban",
+      rowType: Ban.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
+    block: {
+      tableName: "block",
+      rowType: Block.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    cleanup_schedule: {
+      tableName: "cleanup_schedule",
+      rowType: CleanupSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduleId",
+    },
+    client_connection: {
+      tableName: "client_connection",
+      rowType: ClientConnection.getTypeScriptAlgebraicType(),
+      primaryKey: "connectionId",
+    },
     collider: {
       tableName: "collider",
       rowType: Collider.getTypeScriptAlgebraicType(),
       primaryKey: "id",
     },
+    emote_catalog: {
+      tableName: "emote_catalog",
+      rowType: Emote.getTypeScriptAlgebraicType(),
+      primaryKey: "name",
+    },
+    friend_presence_result: {
+      tableName: "friend_presence_result",
+      rowType: FriendPresenceResult.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
+    friendship: {
+      tableName: "friendship",
+      rowType: Friendship.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    idle_check_schedule: {
+      tableName: "idle_check_schedule",
+      rowType: IdleCheckSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduleId",
+    },
+    input_rate_limit: {
+      tableName: "input_rate_limit",
+      rowType: InputRateLimit.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
+    instance: {
+      tableName: "instance",
+      rowType: Instance.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    instance_invite: {
+      tableName: "instance_invite",
+      rowType: InstanceInvite.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    leaderboard_result: {
+      tableName: "leaderboard_result",
+      rowType: LeaderboardResult.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
     logged_out_player: {
       tableName: "logged_out_player",
       rowType: Player.getTypeScriptAlgebraicType(),
       primaryKey: "identity",
     },
+    moderation_flag: {
+      tableName: "moderation_flag",
+      rowType: ModerationFlag.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    mute: {
+      tableName: "mute",
+      rowType: Mute.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
+    mute_expiry_schedule: {
+      tableName: "mute_expiry_schedule",
+      rowType: MuteExpirySchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduleId",
+    },
+    physics_snapshot: {
+      tableName: "physics_snapshot",
+      rowType: PhysicsSnapshot.getTypeScriptAlgebraicType(),
+      primaryKey: "instanceId",
+    },
+    platform: {
+      tableName: "platform",
+      rowType: Platform.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
     player: {
       tableName: "player",
       rowType: Player.getTypeScriptAlgebraicType(),
       primaryKey: "identity",
     },
+    player_stats: {
+      tableName: "player_stats",
+      rowType: PlayerStats.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
+    portal: {
+      tableName: "portal",
+      rowType: Portal.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    portal_cooldown: {
+      tableName: "portal_cooldown",
+      rowType: PortalCooldown.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
+    query_result: {
+      tableName: "query_result",
+      rowType: QueryResult.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
+    recorded_input: {
+      tableName: "recorded_input",
+      rowType: RecordedInput.getTypeScriptAlgebraicType(),
+      primaryKey: "slot",
+    },
+    scene_spawn: {
+      tableName: "scene_spawn",
+      rowType: SceneSpawn.getTypeScriptAlgebraicType(),
+      primaryKey: "sceneVersion",
+    },
+    seat: {
+      tableName: "seat",
+      rowType: Seat.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    session: {
+      tableName: "session",
+      rowType: Session.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    simulation_clock: {
+      tableName: "simulation_clock",
+      rowType: SimulationClock.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
     tick_schedule: {
       tableName: "tick_schedule",
       rowType: TickSchedule.getTypeScriptAlgebraicType(),
       primaryKey: "scheduleId",
     },
+    tracked_pose: {
+      tableName: "tracked_pose",
+      rowType: TrackedPose.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+    },
+    world_settings: {
+      tableName: "world_settings",
+      rowType: WorldSettings.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
   },
   reducers: {
+    accept_friend_request: {
+      reducerName: "accept_friend_request",
+      argsType: AcceptFriendRequest.getTypeScriptAlgebraicType(),
+    },
+    ban_player: {
+      reducerName: "ban_player",
+      argsType: BanPlayer.getTypeScriptAlgebraicType(),
+    },
+    block_user: {
+      reducerName: "block_user",
+      argsType: BlockUser.getTypeScriptAlgebraicType(),
+    },
+    check_idle_players: {
+      reducerName: "check_idle_players",
+      argsType: CheckIdlePlayers.getTypeScriptAlgebraicType(),
+    },
+    clear_input_recording: {
+      reducerName: "clear_input_recording",
+      argsType: ClearInputRecording.getTypeScriptAlgebraicType(),
+    },
+    clear_tracked_pose: {
+      reducerName: "clear_tracked_pose",
+      argsType: ClearTrackedPose.getTypeScriptAlgebraicType(),
+    },
     connect: {
       reducerName: "connect",
       argsType: Connect.getTypeScriptAlgebraicType(),
     },
+    create_platform: {
+      reducerName: "create_platform",
+      argsType: CreatePlatform.getTypeScriptAlgebraicType(),
+    },
+    create_portal: {
+      reducerName: "create_portal",
+      argsType: CreatePortal.getTypeScriptAlgebraicType(),
+    },
+    create_room: {
+      reducerName: "create_room",
+      argsType: CreateRoom.getTypeScriptAlgebraicType(),
+    },
+    create_seat: {
+      reducerName: "create_seat",
+      argsType: CreateSeat.getTypeScriptAlgebraicType(),
+    },
+    delete_room: {
+      reducerName: "delete_room",
+      argsType: DeleteRoom.getTypeScriptAlgebraicType(),
+    },
     disconnect: {
       reducerName: "disconnect",
       argsType: Disconnect.getTypeScriptAlgebraicType(),
     },
+    get_friend_presence: {
+      reducerName: "get_friend_presence",
+      argsType: GetFriendPresence.getTypeScriptAlgebraicType(),
+    },
+    get_leaderboard: {
+      reducerName: "get_leaderboard",
+      argsType: GetLeaderboard.getTypeScriptAlgebraicType(),
+    },
+    grant_admin: {
+      reducerName: "grant_admin",
+      argsType: GrantAdmin.getTypeScriptAlgebraicType(),
+    },
+    invite_to_instance: {
+      reducerName: "invite_to_instance",
+      argsType: InviteToInstance.getTypeScriptAlgebraicType(),
+    },
+    join_friend: {
+      reducerName: "join_friend",
+      argsType: JoinFriend.getTypeScriptAlgebraicType(),
+    },
+    join_instance: {
+      reducerName: "join_instance",
+      argsType: JoinInstance.getTypeScriptAlgebraicType(),
+    },
+    kick: {
+      reducerName: "kick",
+      argsType: Kick.getTypeScriptAlgebraicType(),
+    },
+    mute_player: {
+      reducerName: "mute_player",
+      argsType: MutePlayer.getTypeScriptAlgebraicType(),
+    },
+    play_emote: {
+      reducerName: "play_emote",
+      argsType: PlayEmote.getTypeScriptAlgebraicType(),
+    },
+    point_query: {
+      reducerName: "point_query",
+      argsType: PointQuery.getTypeScriptAlgebraicType(),
+    },
+    purge_logged_out_players: {
+      reducerName: "purge_logged_out_players",
+      argsType: PurgeLoggedOutPlayers.getTypeScriptAlgebraicType(),
+    },
+    raycast: {
+      reducerName: "raycast",
+      argsType: Raycast.getTypeScriptAlgebraicType(),
+    },
+    remove_emote: {
+      reducerName: "remove_emote",
+      argsType: RemoveEmote.getTypeScriptAlgebraicType(),
+    },
+    remove_expired_mutes: {
+      reducerName: "remove_expired_mutes",
+      argsType: RemoveExpiredMutes.getTypeScriptAlgebraicType(),
+    },
+    remove_friend: {
+      reducerName: "remove_friend",
+      argsType: RemoveFriend.getTypeScriptAlgebraicType(),
+    },
+    remove_platform: {
+      reducerName: "remove_platform",
+      argsType: RemovePlatform.getTypeScriptAlgebraicType(),
+    },
+    remove_portal: {
+      reducerName: "remove_portal",
+      argsType: RemovePortal.getTypeScriptAlgebraicType(),
+    },
+    remove_seat: {
+      reducerName: "remove_seat",
+      argsType: RemoveSeat.getTypeScriptAlgebraicType(),
+    },
+    revoke_admin: {
+      reducerName: "revoke_admin",
+      argsType: RevokeAdmin.getTypeScriptAlgebraicType(),
+    },
+    revoke_invite: {
+      reducerName: "revoke_invite",
+      argsType: RevokeInvite.getTypeScriptAlgebraicType(),
+    },
+    send_friend_request: {
+      reducerName: "send_friend_request",
+      argsType: SendFriendRequest.getTypeScriptAlgebraicType(),
+    },
+    set_collider_material: {
+      reducerName: "set_collider_material",
+      argsType: SetColliderMaterial.getTypeScriptAlgebraicType(),
+    },
+    set_collider_volume: {
+      reducerName: "set_collider_volume",
+      argsType: SetColliderVolume.getTypeScriptAlgebraicType(),
+    },
+    set_default_scene: {
+      reducerName: "set_default_scene",
+      argsType: SetDefaultScene.getTypeScriptAlgebraicType(),
+    },
+    set_emote: {
+      reducerName: "set_emote",
+      argsType: SetEmote.getTypeScriptAlgebraicType(),
+    },
+    set_idle_timeouts: {
+      reducerName: "set_idle_timeouts",
+      argsType: SetIdleTimeouts.getTypeScriptAlgebraicType(),
+    },
+    set_input_recording: {
+      reducerName: "set_input_recording",
+      argsType: SetInputRecording.getTypeScriptAlgebraicType(),
+    },
+    set_instance_visibility: {
+      reducerName: "set_instance_visibility",
+      argsType: SetInstanceVisibility.getTypeScriptAlgebraicType(),
+    },
+    set_logged_out_retention: {
+      reducerName: "set_logged_out_retention",
+      argsType: SetLoggedOutRetention.getTypeScriptAlgebraicType(),
+    },
+    set_persistent_profile: {
+      reducerName: "set_persistent_profile",
+      argsType: SetPersistentProfile.getTypeScriptAlgebraicType(),
+    },
+    set_personal_space: {
+      reducerName: "set_personal_space",
+      argsType: SetPersonalSpace.getTypeScriptAlgebraicType(),
+    },
+    set_player_collisions: {
+      reducerName: "set_player_collisions",
+      argsType: SetPlayerCollisions.getTypeScriptAlgebraicType(),
+    },
+    set_player_movement_mode: {
+      reducerName: "set_player_movement_mode",
+      argsType: SetPlayerMovementMode.getTypeScriptAlgebraicType(),
+    },
+    set_scene_spawn_point: {
+      reducerName: "set_scene_spawn_point",
+      argsType: SetSceneSpawnPoint.getTypeScriptAlgebraicType(),
+    },
+    set_world_player_collisions: {
+      reducerName: "set_world_player_collisions",
+      argsType: SetWorldPlayerCollisions.getTypeScriptAlgebraicType(),
+    },
+    shape_cast: {
+      reducerName: "shape_cast",
+      argsType: ShapeCast.getTypeScriptAlgebraicType(),
+    },
+    sit: {
+      reducerName: "sit",
+      argsType: Sit.getTypeScriptAlgebraicType(),
+    },
+    snapshot_physics: {
+      reducerName: "snapshot_physics",
+      argsType: SnapshotPhysics.getTypeScriptAlgebraicType(),
+    },
+    stand: {
+      reducerName: "stand",
+      argsType: Stand.getTypeScriptAlgebraicType(),
+    },
     tick: {
       reducerName: "tick",
       argsType: Tick.getTypeScriptAlgebraicType(),
     },
+    unban_player: {
+      reducerName: "unban_player",
+      argsType: UnbanPlayer.getTypeScriptAlgebraicType(),
+    },
+    unblock_user: {
+      reducerName: "unblock_user",
+      argsType: UnblockUser.getTypeScriptAlgebraicType(),
+    },
+    unmute_player: {
+      reducerName: "unmute_player",
+      argsType: UnmutePlayer.getTypeScriptAlgebraicType(),
+    },
     update_player_input: {
       reducerName: "update_player_input",
       argsType: UpdatePlayerInput.getTypeScriptAlgebraicType(),
     },
+    update_tracked_pose: {
+      reducerName: "update_tracked_pose",
+      argsType: UpdateTrackedPose.getTypeScriptAlgebraicType(),
+    },
     upload_body: {
       reducerName: "upload_body",
       argsType: UploadBody.getTypeScriptAlgebraicType(),
@@ -136,16 +764,166 @@ const REMOTE_MODULE = {
 
 // A type representing all the possible variants of a reducer.
 export type Reducer = never
+| { name: "AcceptFriendRequest", args: AcceptFriendRequest }
+| { name: "BanPlayer", args: BanPlayer }
+| { name: "BlockUser", args: BlockUser }
+| { name: "CheckIdlePlayers", args: CheckIdlePlayers }
+| { name: "ClearInputRecording", args: ClearInputRecording }
+| { name: "ClearTrackedPose", args: ClearTrackedPose }
 | { name: "Connect", args: Connect }
+| { name: "CreatePlatform", args: CreatePlatform }
+| { name: "CreatePortal", args: CreatePortal }
+| { name: "CreateRoom", args: CreateRoom }
+| { name: "CreateSeat", args: CreateSeat }
+| { name: "DeleteRoom", args: DeleteRoom }
 | { name: "Disconnect", args: Disconnect }
+| { name: "GetFriendPresence", args: GetFriendPresence }
+| { name: "GetLeaderboard", args: GetLeaderboard }
+| { name: "GrantAdmin", args: GrantAdmin }
+| { name: "InviteToInstance", args: InviteToInstance }
+| { name: "JoinFriend", args: JoinFriend }
+| { name: "JoinInstance", args: JoinInstance }
+| { name: "Kick", args: Kick }
+| { name: "MutePlayer", args: MutePlayer }
+| { name: "PlayEmote", args: PlayEmote }
+| { name: "PointQuery", args: PointQuery }
+| { name: "PurgeLoggedOutPlayers", args: PurgeLoggedOutPlayers }
+| { name: "Raycast", args: Raycast }
+| { name: "RemoveEmote", args: RemoveEmote }
+| { name: "RemoveExpiredMutes", args: RemoveExpiredMutes }
+| { name: "RemoveFriend", args: RemoveFriend }
+| { name: "RemovePlatform", args: RemovePlatform }
+| { name: "RemovePortal", args: RemovePortal }
+| { name: "RemoveSeat", args: RemoveSeat }
+| { name: "RevokeAdmin", args: RevokeAdmin }
+| { name: "RevokeInvite", args: RevokeInvite }
+| { name: "SendFriendRequest", args: SendFriendRequest }
+| { name: "SetColliderMaterial", args: SetColliderMaterial }
+| { name: "SetColliderVolume", args: SetColliderVolume }
+| { name: "SetDefaultScene", args: SetDefaultScene }
+| { name: "SetEmote", args: SetEmote }
+| { name: "SetIdleTimeouts", args: SetIdleTimeouts }
+| { name: "SetInputRecording", args: SetInputRecording }
+| { name: "SetInstanceVisibility", args: SetInstanceVisibility }
+| { name: "SetLoggedOutRetention", args: SetLoggedOutRetention }
+| { name: "SetPersistentProfile", args: SetPersistentProfile }
+| { name: "SetPersonalSpace", args: SetPersonalSpace }
+| { name: "SetPlayerCollisions", args: SetPlayerCollisions }
+| { name: "SetPlayerMovementMode", args: SetPlayerMovementMode }
+| { name: "SetSceneSpawnPoint", args: SetSceneSpawnPoint }
+| { name: "SetWorldPlayerCollisions", args: SetWorldPlayerCollisions }
+| { name: "ShapeCast", args: ShapeCast }
+| { name: "Sit", args: Sit }
+| { name: "SnapshotPhysics", args: SnapshotPhysics }
+| { name: "Stand", args: Stand }
 | { name: "Tick", args: Tick }
+| { name: "UnbanPlayer", args: UnbanPlayer }
+| { name: "UnblockUser", args: UnblockUser }
+| { name: "UnmutePlayer", args: UnmutePlayer }
 | { name: "UpdatePlayerInput", args: UpdatePlayerInput }
+| { name: "UpdateTrackedPose", args: UpdateTrackedPose }
 | { name: "UploadBody", args: UploadBody }
 ;
 
 export class RemoteReducers {
   constructor(private connection: DbConnectionImpl, private setCallReducerFlags: SetReducerFlags) {}
 
+  acceptFriendRequest(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    AcceptFriendRequest.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("accept_friend_request", __argsBuffer, this.setCallReducerFlags.acceptFriendRequestFlags);
+  }
+
+  onAcceptFriendRequest(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("accept_friend_request", callback);
+  }
+
+  removeOnAcceptFriendRequest(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("accept_friend_request", callback);
+  }
+
+  banPlayer(identity: Identity, reason: string, durationSecs: bigint | undefined) {
+    const __args = { identity, reason, durationSecs };
+    let __writer = new BinaryWriter(1024);
+    BanPlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("ban_player", __argsBuffer, this.setCallReducerFlags.banPlayerFlags);
+  }
+
+  onBanPlayer(callback: (ctx: ReducerEventContext, identity: Identity, reason: string, durationSecs: bigint | undefined) => void) {
+    this.connection.onReducer("ban_player", callback);
+  }
+
+  removeOnBanPlayer(callback: (ctx: ReducerEventContext, identity: Identity, reason: string, durationSecs: bigint | undefined) => void) {
+    this.connection.offReducer("ban_player", callback);
+  }
+
+  blockUser(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    BlockUser.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("block_user", __argsBuffer, this.setCallReducerFlags.blockUserFlags);
+  }
+
+  onBlockUser(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("block_user", callback);
+  }
+
+  removeOnBlockUser(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("block_user", callback);
+  }
+
+  checkIdlePlayers(schedule: IdleCheckSchedule) {
+    const __args = { schedule };
+    let __writer = new BinaryWriter(1024);
+    CheckIdlePlayers.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("check_idle_players", __argsBuffer, this.setCallReducerFlags.checkIdlePlayersFlags);
+  }
+
+  onCheckIdlePlayers(callback: (ctx: ReducerEventContext, schedule: IdleCheckSchedule) => void) {
+    this.connection.onReducer("check_idle_players", callback);
+  }
+
+  removeOnCheckIdlePlayers(callback: (ctx: ReducerEventContext, schedule: IdleCheckSchedule) => void) {
+    this.connection.offReducer("check_idle_players", callback);
+  }
+
+  clearInputRecording() {
+    const __args = {};
+    let __writer = new BinaryWriter(1024);
+    ClearInputRecording.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("clear_input_recording", __argsBuffer, this.setCallReducerFlags.clearInputRecordingFlags);
+  }
+
+  onClearInputRecording(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("clear_input_recording", callback);
+  }
+
+  removeOnClearInputRecording(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("clear_input_recording", callback);
+  }
+
+  clearTrackedPose() {
+    const __args = {};
+    let __writer = new BinaryWriter(1024);
+    ClearTrackedPose.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("clear_tracked_pose", __argsBuffer, this.setCallReducerFlags.clearTrackedPoseFlags);
+  }
+
+  onClearTrackedPose(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("clear_tracked_pose", callback);
+  }
+
+  removeOnClearTrackedPose(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("clear_tracked_pose", callback);
+  }
+
   onConnect(callback: (ctx: ReducerEventContext) => void) {
     this.connection.onReducer("connect", callback);
   }
@@ -154,6 +932,86 @@ export class RemoteReducers {
     this.connection.offReducer("connect", callback);
   }
 
+  createPlatform(colliderName: string, sceneVersion: number, keyframes: PlatformKeyframe[]) {
+    const __args = { colliderName, sceneVersion, keyframes };
+    let __writer = new BinaryWriter(1024);
+    CreatePlatform.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_platform", __argsBuffer, this.setCallReducerFlags.createPlatformFlags);
+  }
+
+  onCreatePlatform(callback: (ctx: ReducerEventContext, colliderName: string, sceneVersion: number, keyframes: PlatformKeyframe[]) => void) {
+    this.connection.onReducer("create_platform", callback);
+  }
+
+  removeOnCreatePlatform(callback: (ctx: ReducerEventContext, colliderName: string, sceneVersion: number, keyframes: PlatformKeyframe[]) => void) {
+    this.connection.offReducer("create_platform", callback);
+  }
+
+  createPortal(instanceId: number, position: DbVector3, halfExtents: DbVector3, destinationInstanceId: number, destinationSpawn: DbVector3) {
+    const __args = { instanceId, position, halfExtents, destinationInstanceId, destinationSpawn };
+    let __writer = new BinaryWriter(1024);
+    CreatePortal.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_portal", __argsBuffer, this.setCallReducerFlags.createPortalFlags);
+  }
+
+  onCreatePortal(callback: (ctx: ReducerEventContext, instanceId: number, position: DbVector3, halfExtents: DbVector3, destinationInstanceId: number, destinationSpawn: DbVector3) => void) {
+    this.connection.onReducer("create_portal", callback);
+  }
+
+  removeOnCreatePortal(callback: (ctx: ReducerEventContext, instanceId: number, position: DbVector3, halfExtents: DbVector3, destinationInstanceId: number, destinationSpawn: DbVector3) => void) {
+    this.connection.offReducer("create_portal", callback);
+  }
+
+  createRoom(name: string, sceneVersion: number, capacity: number, visibility: InstanceVisibility) {
+    const __args = { name, sceneVersion, capacity, visibility };
+    let __writer = new BinaryWriter(1024);
+    CreateRoom.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_room", __argsBuffer, this.setCallReducerFlags.createRoomFlags);
+  }
+
+  onCreateRoom(callback: (ctx: ReducerEventContext, name: string, sceneVersion: number, capacity: number, visibility: InstanceVisibility) => void) {
+    this.connection.onReducer("create_room", callback);
+  }
+
+  removeOnCreateRoom(callback: (ctx: ReducerEventContext, name: string, sceneVersion: number, capacity: number, visibility: InstanceVisibility) => void) {
+    this.connection.offReducer("create_room", callback);
+  }
+
+  createSeat(instanceId: number, position: DbVector3, facing: number) {
+    const __args = { instanceId, position, facing };
+    let __writer = new BinaryWriter(1024);
+    CreateSeat.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_seat", __argsBuffer, this.setCallReducerFlags.createSeatFlags);
+  }
+
+  onCreateSeat(callback: (ctx: ReducerEventContext, instanceId: number, position: DbVector3, facing: number) => void) {
+    this.connection.onReducer("create_seat", callback);
+  }
+
+  removeOnCreateSeat(callback: (ctx: ReducerEventContext, instanceId: number, position: DbVector3, facing: number) => void) {
+    this.connection.offReducer("create_seat", callback);
+  }
+
+  deleteRoom(instanceId: number) {
+    const __args = { instanceId };
+    let __writer = new BinaryWriter(1024);
+    DeleteRoom.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_room", __argsBuffer, this.setCallReducerFlags.deleteRoomFlags);
+  }
+
+  onDeleteRoom(callback: (ctx: ReducerEventContext, instanceId: number) => void) {
+    this.connection.onReducer("delete_room", callback);
+  }
+
+  removeOnDeleteRoom(callback: (ctx: ReducerEventContext, instanceId: number) => void) {
+    this.connection.offReducer("delete_room", callback);
+  }
+
   onDisconnect(callback: (ctx: ReducerEventContext) => void) {
     this.connection.onReducer("disconnect", callback);
   }
@@ -162,91 +1020,1169 @@ export class RemoteReducers {
     this.connection.offReducer("disconnect", callback);
   }
 
-  tick(schedule: TickSchedule) {
-    const __args = { schedule };
+  getFriendPresence() {
+    const __args = {};
     let __writer = new BinaryWriter(1024);
-    Tick.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    GetFriendPresence.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("tick", __argsBuffer, this.setCallReducerFlags.tickFlags);
+    this.connection.callReducer("get_friend_presence", __argsBuffer, this.setCallReducerFlags.getFriendPresenceFlags);
   }
 
-  onTick(callback: (ctx: ReducerEventContext, schedule: TickSchedule) => void) {
-    this.connection.onReducer("tick", callback);
+  onGetFriendPresence(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("get_friend_presence", callback);
   }
 
-  removeOnTick(callback: (ctx: ReducerEventContext, schedule: TickSchedule) => void) {
-    this.connection.offReducer("tick", callback);
+  removeOnGetFriendPresence(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("get_friend_presence", callback);
   }
 
-  updatePlayerInput(input: InputState, rotation: number) {
-    const __args = { input, rotation };
+  getLeaderboard(metric: LeaderboardMetric, limit: number) {
+    const __args = { metric, limit };
     let __writer = new BinaryWriter(1024);
-    UpdatePlayerInput.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    GetLeaderboard.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("update_player_input", __argsBuffer, this.setCallReducerFlags.updatePlayerInputFlags);
+    this.connection.callReducer("get_leaderboard", __argsBuffer, this.setCallReducerFlags.getLeaderboardFlags);
   }
 
-  onUpdatePlayerInput(callback: (ctx: ReducerEventContext, input: InputState, rotation: number) => void) {
-    this.connection.onReducer("update_player_input", callback);
+  onGetLeaderboard(callback: (ctx: ReducerEventContext, metric: LeaderboardMetric, limit: number) => void) {
+    this.connection.onReducer("get_leaderboard", callback);
   }
 
-  removeOnUpdatePlayerInput(callback: (ctx: ReducerEventContext, input: InputState, rotation: number) => void) {
-    this.connection.offReducer("update_player_input", callback);
+  removeOnGetLeaderboard(callback: (ctx: ReducerEventContext, metric: LeaderboardMetric, limit: number) => void) {
+    this.connection.offReducer("get_leaderboard", callback);
   }
 
-  uploadBody(points: DbVector3[], indices: DbVector3[], name: string) {
-    const __args = { points, indices, name };
+  grantAdmin(identity: Identity) {
+    const __args = { identity };
     let __writer = new BinaryWriter(1024);
-    UploadBody.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    GrantAdmin.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("upload_body", __argsBuffer, this.setCallReducerFlags.uploadBodyFlags);
+    this.connection.callReducer("grant_admin", __argsBuffer, this.setCallReducerFlags.grantAdminFlags);
   }
 
-  onUploadBody(callback: (ctx: ReducerEventContext, points: DbVector3[], indices: DbVector3[], name: string) => void) {
-    this.connection.onReducer("upload_body", callback);
+  onGrantAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("grant_admin", callback);
   }
 
-  removeOnUploadBody(callback: (ctx: ReducerEventContext, points: DbVector3[], indices: DbVector3[], name: string) => void) {
-    this.connection.offReducer("upload_body", callback);
+  removeOnGrantAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("grant_admin", callback);
   }
 
-}
+  inviteToInstance(instanceId: number, identity: Identity) {
+    const __args = { instanceId, identity };
+    let __writer = new BinaryWriter(1024);
+    InviteToInstance.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("invite_to_instance", __argsBuffer, this.setCallReducerFlags.inviteToInstanceFlags);
+  }
 
-export class SetReducerFlags {
-  tickFlags: CallReducerFlags = 'FullUpdate';
-  tick(flags: CallReducerFlags) {
-    this.tickFlags = flags;
+  onInviteToInstance(callback: (ctx: ReducerEventContext, instanceId: number, identity: Identity) => void) {
+    this.connection.onReducer("invite_to_instance", callback);
   }
 
-  updatePlayerInputFlags: CallReducerFlags = 'FullUpdate';
-  updatePlayerInput(flags: CallReducerFlags) {
-    this.updatePlayerInputFlags = flags;
+  removeOnInviteToInstance(callback: (ctx: ReducerEventContext, instanceId: number, identity: Identity) => void) {
+    this.connection.offReducer("invite_to_instance", callback);
   }
 
-  uploadBodyFlags: CallReducerFlags = 'FullUpdate';
-  uploadBody(flags: CallReducerFlags) {
-    this.uploadBodyFlags = flags;
+  joinFriend(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    JoinFriend.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("join_friend", __argsBuffer, this.setCallReducerFlags.joinFriendFlags);
   }
 
-}
+  onJoinFriend(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("join_friend", callback);
+  }
 
-export class RemoteTables {
-  constructor(private connection: DbConnectionImpl) {}
+  removeOnJoinFriend(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("join_friend", callback);
+  }
 
-  get collider(): ColliderTableHandle {
-    return new ColliderTableHandle(this.connection.clientCache.getOrCreateTable<Collider>(REMOTE_MODULE.tables.collider));
+  joinInstance(instanceId: number) {
+    const __args = { instanceId };
+    let __writer = new BinaryWriter(1024);
+    JoinInstance.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("join_instance", __argsBuffer, this.setCallReducerFlags.joinInstanceFlags);
   }
 
-  get loggedOutPlayer(): LoggedOutPlayerTableHandle {
-    return new LoggedOutPlayerTableHandle(this.connection.clientCache.getOrCreateTable<Player>(REMOTE_MODULE.tables.logged_out_player));
+  onJoinInstance(callback: (ctx: ReducerEventContext, instanceId: number) => void) {
+    this.connection.onReducer("join_instance", callback);
   }
 
-  get player(): PlayerTableHandle {
-    return new PlayerTableHandle(this.connection.clientCache.getOrCreateTable<Player>(REMOTE_MODULE.tables.player));
+  removeOnJoinInstance(callback: (ctx: ReducerEventContext, instanceId: number) => void) {
+    this.connection.offReducer("join_instance", callback);
   }
 
-  get tickSchedule(): TickScheduleTableHandle {
-    return new TickScheduleTableHandle(this.connection.clientCache.getOrCreateTable<TickSchedule>(REMOTE_MODULE.tables.tick_schedule));
+  kick(identity: Identity, reason: string) {
+    const __args = { identity, reason };
+    let __writer = new BinaryWriter(1024);
+    Kick.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("kick", __argsBuffer, this.setCallReducerFlags.kickFlags);
+  }
+
+  onKick(callback: (ctx: ReducerEventContext, identity: Identity, reason: string) => void) {
+    this.connection.onReducer("kick", callback);
+  }
+
+  removeOnKick(callback: (ctx: ReducerEventContext, identity: Identity, reason: string) => void) {
+    this.connection.offReducer("kick", callback);
+  }
+
+  mutePlayer(identity: Identity, chat: boolean, voice: boolean, reason: string, durationSecs: bigint) {
+    const __args = { identity, chat, voice, reason, durationSecs };
+    let __writer = new BinaryWriter(1024);
+    MutePlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("mute_player", __argsBuffer, this.setCallReducerFlags.mutePlayerFlags);
+  }
+
+  onMutePlayer(callback: (ctx: ReducerEventContext, identity: Identity, chat: boolean, voice: boolean, reason: string, durationSecs: bigint) => void) {
+    this.connection.onReducer("mute_player", callback);
+  }
+
+  removeOnMutePlayer(callback: (ctx: ReducerEventContext, identity: Identity, chat: boolean, voice: boolean, reason: string, durationSecs: bigint) => void) {
+    this.connection.offReducer("mute_player", callback);
+  }
+
+  playEmote(name: string) {
+    const __args = { name };
+    let __writer = new BinaryWriter(1024);
+    PlayEmote.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("play_emote", __argsBuffer, this.setCallReducerFlags.playEmoteFlags);
+  }
+
+  onPlayEmote(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.onReducer("play_emote", callback);
+  }
+
+  removeOnPlayEmote(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.offReducer("play_emote", callback);
+  }
+
+  pointQuery(requestId: number, point: DbVector3, includeVolumes: boolean) {
+    const __args = { requestId, point, includeVolumes };
+    let __writer = new BinaryWriter(1024);
+    PointQuery.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("point_query", __argsBuffer, this.setCallReducerFlags.pointQueryFlags);
+  }
+
+  onPointQuery(callback: (ctx: ReducerEventContext, requestId: number, point: DbVector3, includeVolumes: boolean) => void) {
+    this.connection.onReducer("point_query", callback);
+  }
+
+  removeOnPointQuery(callback: (ctx: ReducerEventContext, requestId: number, point: DbVector3, includeVolumes: boolean) => void) {
+    this.connection.offReducer("point_query", callback);
+  }
+
+  purgeLoggedOutPlayers(schedule: CleanupSchedule) {
+    const __args = { schedule };
+    let __writer = new BinaryWriter(1024);
+    PurgeLoggedOutPlayers.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("purge_logged_out_players", __argsBuffer, this.setCallReducerFlags.purgeLoggedOutPlayersFlags);
+  }
+
+  onPurgeLoggedOutPlayers(callback: (ctx: ReducerEventContext, schedule: CleanupSchedule) => void) {
+    this.connection.onReducer("purge_logged_out_players", callback);
+  }
+
+  removeOnPurgeLoggedOutPlayers(callback: (ctx: ReducerEventContext, schedule: CleanupSchedule) => void) {
+    this.connection.offReducer("purge_logged_out_players", callback);
+  }
+
+  raycast(requestId: number, origin: DbVector3, direction: DbVector3, maxDistance: number) {
+    const __args = { requestId, origin, direction, maxDistance };
+    let __writer = new BinaryWriter(1024);
+    Raycast.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("raycast", __argsBuffer, this.setCallReducerFlags.raycastFlags);
+  }
+
+  onRaycast(callback: (ctx: ReducerEventContext, requestId: number, origin: DbVector3, direction: DbVector3, maxDistance: number) => void) {
+    this.connection.onReducer("raycast", callback);
+  }
+
+  removeOnRaycast(callback: (ctx: ReducerEventContext, requestId: number, origin: DbVector3, direction: DbVector3, maxDistance: number) => void) {
+    this.connection.offReducer("raycast", callback);
+  }
+
+  removeEmote(name: string) {
+    const __args = { name };
+    let __writer = new BinaryWriter(1024);
+    RemoveEmote.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_emote", __argsBuffer, this.setCallReducerFlags.removeEmoteFlags);
+  }
+
+  onRemoveEmote(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.onReducer("remove_emote", callback);
+  }
+
+  removeOnRemoveEmote(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.offReducer("remove_emote", callback);
+  }
+
+  removeExpiredMutes(schedule: MuteExpirySchedule) {
+    const __args = { schedule };
+    let __writer = new BinaryWriter(1024);
+    RemoveExpiredMutes.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_expired_mutes", __argsBuffer, this.setCallReducerFlags.removeExpiredMutesFlags);
+  }
+
+  onRemoveExpiredMutes(callback: (ctx: ReducerEventContext, schedule: MuteExpirySchedule) => void) {
+    this.connection.onReducer("remove_expired_mutes", callback);
+  }
+
+  removeOnRemoveExpiredMutes(callback: (ctx: ReducerEventContext, schedule: MuteExpirySchedule) => void) {
+    this.connection.offReducer("remove_expired_mutes", callback);
+  }
+
+  removeFriend(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    RemoveFriend.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_friend", __argsBuffer, this.setCallReducerFlags.removeFriendFlags);
+  }
+
+  onRemoveFriend(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("remove_friend", callback);
+  }
+
+  removeOnRemoveFriend(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("remove_friend", callback);
+  }
+
+  removePlatform(platformId: number) {
+    const __args = { platformId };
+    let __writer = new BinaryWriter(1024);
+    RemovePlatform.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_platform", __argsBuffer, this.setCallReducerFlags.removePlatformFlags);
+  }
+
+  onRemovePlatform(callback: (ctx: ReducerEventContext, platformId: number) => void) {
+    this.connection.onReducer("remove_platform", callback);
+  }
+
+  removeOnRemovePlatform(callback: (ctx: ReducerEventContext, platformId: number) => void) {
+    this.connection.offReducer("remove_platform", callback);
+  }
+
+  removePortal(portalId: number) {
+    const __args = { portalId };
+    let __writer = new BinaryWriter(1024);
+    RemovePortal.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_portal", __argsBuffer, this.setCallReducerFlags.removePortalFlags);
+  }
+
+  onRemovePortal(callback: (ctx: ReducerEventContext, portalId: number) => void) {
+    this.connection.onReducer("remove_portal", callback);
+  }
+
+  removeOnRemovePortal(callback: (ctx: ReducerEventContext, portalId: number) => void) {
+    this.connection.offReducer("remove_portal", callback);
+  }
+
+  removeSeat(seatId: number) {
+    const __args = { seatId };
+    let __writer = new BinaryWriter(1024);
+    RemoveSeat.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_seat", __argsBuffer, this.setCallReducerFlags.removeSeatFlags);
+  }
+
+  onRemoveSeat(callback: (ctx: ReducerEventContext, seatId: number) => void) {
+    this.connection.onReducer("remove_seat", callback);
+  }
+
+  removeOnRemoveSeat(callback: (ctx: ReducerEventContext, seatId: number) => void) {
+    this.connection.offReducer("remove_seat", callback);
+  }
+
+  revokeAdmin(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    RevokeAdmin.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("revoke_admin", __argsBuffer, this.setCallReducerFlags.revokeAdminFlags);
+  }
+
+  onRevokeAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("revoke_admin", callback);
+  }
+
+  removeOnRevokeAdmin(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("revoke_admin", callback);
+  }
+
+  revokeInvite(instanceId: number, identity: Identity) {
+    const __args = { instanceId, identity };
+    let __writer = new BinaryWriter(1024);
+    RevokeInvite.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("revoke_invite", __argsBuffer, this.setCallReducerFlags.revokeInviteFlags);
+  }
+
+  onRevokeInvite(callback: (ctx: ReducerEventContext, instanceId: number, identity: Identity) => void) {
+    this.connection.onReducer("revoke_invite", callback);
+  }
+
+  removeOnRevokeInvite(callback: (ctx: ReducerEventContext, instanceId: number, identity: Identity) => void) {
+    this.connection.offReducer("revoke_invite", callback);
+  }
+
+  sendFriendRequest(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    SendFriendRequest.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_friend_request", __argsBuffer, this.setCallReducerFlags.sendFriendRequestFlags);
+  }
+
+  onSendFriendRequest(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("send_friend_request", callback);
+  }
+
+  removeOnSendFriendRequest(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("send_friend_request", callback);
+  }
+
+  setColliderMaterial(colliderId: number, material: SurfaceMaterial) {
+    const __args = { colliderId, material };
+    let __writer = new BinaryWriter(1024);
+    SetColliderMaterial.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_collider_material", __argsBuffer, this.setCallReducerFlags.setColliderMaterialFlags);
+  }
+
+  onSetColliderMaterial(callback: (ctx: ReducerEventContext, colliderId: number, material: SurfaceMaterial) => void) {
+    this.connection.onReducer("set_collider_material", callback);
+  }
+
+  removeOnSetColliderMaterial(callback: (ctx: ReducerEventContext, colliderId: number, material: SurfaceMaterial) => void) {
+    this.connection.offReducer("set_collider_material", callback);
+  }
+
+  setColliderVolume(colliderId: number, volume: VolumeType | undefined) {
+    const __args = { colliderId, volume };
+    let __writer = new BinaryWriter(1024);
+    SetColliderVolume.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_collider_volume", __argsBuffer, this.setCallReducerFlags.setColliderVolumeFlags);
+  }
+
+  onSetColliderVolume(callback: (ctx: ReducerEventContext, colliderId: number, volume: VolumeType | undefined) => void) {
+    this.connection.onReducer("set_collider_volume", callback);
+  }
+
+  removeOnSetColliderVolume(callback: (ctx: ReducerEventContext, colliderId: number, volume: VolumeType | undefined) => void) {
+    this.connection.offReducer("set_collider_volume", callback);
+  }
+
+  setDefaultScene(sceneVersion: number, instanceCapacity: number) {
+    const __args = { sceneVersion, instanceCapacity };
+    let __writer = new BinaryWriter(1024);
+    SetDefaultScene.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_default_scene", __argsBuffer, this.setCallReducerFlags.setDefaultSceneFlags);
+  }
+
+  onSetDefaultScene(callback: (ctx: ReducerEventContext, sceneVersion: number, instanceCapacity: number) => void) {
+    this.connection.onReducer("set_default_scene", callback);
+  }
+
+  removeOnSetDefaultScene(callback: (ctx: ReducerEventContext, sceneVersion: number, instanceCapacity: number) => void) {
+    this.connection.offReducer("set_default_scene", callback);
+  }
+
+  setEmote(name: string, durationSecs: number) {
+    const __args = { name, durationSecs };
+    let __writer = new BinaryWriter(1024);
+    SetEmote.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_emote", __argsBuffer, this.setCallReducerFlags.setEmoteFlags);
+  }
+
+  onSetEmote(callback: (ctx: ReducerEventContext, name: string, durationSecs: number) => void) {
+    this.connection.onReducer("set_emote", callback);
+  }
+
+  removeOnSetEmote(callback: (ctx: ReducerEventContext, name: string, durationSecs: number) => void) {
+    this.connection.offReducer("set_emote", callback);
+  }
+
+  setIdleTimeouts(afkTimeoutSecs: number, evictTimeoutSecs: number) {
+    const __args = { afkTimeoutSecs, evictTimeoutSecs };
+    let __writer = new BinaryWriter(1024);
+    SetIdleTimeouts.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_idle_timeouts", __argsBuffer, this.setCallReducerFlags.setIdleTimeoutsFlags);
+  }
+
+  onSetIdleTimeouts(callback: (ctx: ReducerEventContext, afkTimeoutSecs: number, evictTimeoutSecs: number) => void) {
+    this.connection.onReducer("set_idle_timeouts", callback);
+  }
+
+  removeOnSetIdleTimeouts(callback: (ctx: ReducerEventContext, afkTimeoutSecs: number, evictTimeoutSecs: number) => void) {
+    this.connection.offReducer("set_idle_timeouts", callback);
+  }
+
+  setInputRecording(enabled: boolean, bufferSize: number) {
+    const __args = { enabled, bufferSize };
+    let __writer = new BinaryWriter(1024);
+    SetInputRecording.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_input_recording", __argsBuffer, this.setCallReducerFlags.setInputRecordingFlags);
+  }
+
+  onSetInputRecording(callback: (ctx: ReducerEventContext, enabled: boolean, bufferSize: number) => void) {
+    this.connection.onReducer("set_input_recording", callback);
+  }
+
+  removeOnSetInputRecording(callback: (ctx: ReducerEventContext, enabled: boolean, bufferSize: number) => void) {
+    this.connection.offReducer("set_input_recording", callback);
+  }
+
+  setInstanceVisibility(instanceId: number, visibility: InstanceVisibility) {
+    const __args = { instanceId, visibility };
+    let __writer = new BinaryWriter(1024);
+    SetInstanceVisibility.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_instance_visibility", __argsBuffer, this.setCallReducerFlags.setInstanceVisibilityFlags);
+  }
+
+  onSetInstanceVisibility(callback: (ctx: ReducerEventContext, instanceId: number, visibility: InstanceVisibility) => void) {
+    this.connection.onReducer("set_instance_visibility", callback);
+  }
+
+  removeOnSetInstanceVisibility(callback: (ctx: ReducerEventContext, instanceId: number, visibility: InstanceVisibility) => void) {
+    this.connection.offReducer("set_instance_visibility", callback);
+  }
+
+  setLoggedOutRetention(retentionSecs: number) {
+    const __args = { retentionSecs };
+    let __writer = new BinaryWriter(1024);
+    SetLoggedOutRetention.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_logged_out_retention", __argsBuffer, this.setCallReducerFlags.setLoggedOutRetentionFlags);
+  }
+
+  onSetLoggedOutRetention(callback: (ctx: ReducerEventContext, retentionSecs: number) => void) {
+    this.connection.onReducer("set_logged_out_retention", callback);
+  }
+
+  removeOnSetLoggedOutRetention(callback: (ctx: ReducerEventContext, retentionSecs: number) => void) {
+    this.connection.offReducer("set_logged_out_retention", callback);
+  }
+
+  setPersistentProfile(enabled: boolean) {
+    const __args = { enabled };
+    let __writer = new BinaryWriter(1024);
+    SetPersistentProfile.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_persistent_profile", __argsBuffer, this.setCallReducerFlags.setPersistentProfileFlags);
+  }
+
+  onSetPersistentProfile(callback: (ctx: ReducerEventContext, enabled: boolean) => void) {
+    this.connection.onReducer("set_persistent_profile", callback);
+  }
+
+  removeOnSetPersistentProfile(callback: (ctx: ReducerEventContext, enabled: boolean) => void) {
+    this.connection.offReducer("set_persistent_profile", callback);
+  }
+
+  setPersonalSpace(radius: number | undefined) {
+    const __args = { radius };
+    let __writer = new BinaryWriter(1024);
+    SetPersonalSpace.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_personal_space", __argsBuffer, this.setCallReducerFlags.setPersonalSpaceFlags);
+  }
+
+  onSetPersonalSpace(callback: (ctx: ReducerEventContext, radius: number | undefined) => void) {
+    this.connection.onReducer("set_personal_space", callback);
+  }
+
+  removeOnSetPersonalSpace(callback: (ctx: ReducerEventContext, radius: number | undefined) => void) {
+    this.connection.offReducer("set_personal_space", callback);
+  }
+
+  setPlayerCollisions(enabled: boolean) {
+    const __args = { enabled };
+    let __writer = new BinaryWriter(1024);
+    SetPlayerCollisions.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_player_collisions", __argsBuffer, this.setCallReducerFlags.setPlayerCollisionsFlags);
+  }
+
+  onSetPlayerCollisions(callback: (ctx: ReducerEventContext, enabled: boolean) => void) {
+    this.connection.onReducer("set_player_collisions", callback);
+  }
+
+  removeOnSetPlayerCollisions(callback: (ctx: ReducerEventContext, enabled: boolean) => void) {
+    this.connection.offReducer("set_player_collisions", callback);
+  }
+
+  setPlayerMovementMode(identity: Identity, mode: MovementMode) {
+    const __args = { identity, mode };
+    let __writer = new BinaryWriter(1024);
+    SetPlayerMovementMode.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_player_movement_mode", __argsBuffer, this.setCallReducerFlags.setPlayerMovementModeFlags);
+  }
+
+  onSetPlayerMovementMode(callback: (ctx: ReducerEventContext, identity: Identity, mode: MovementMode) => void) {
+    this.connection.onReducer("set_player_movement_mode", callback);
+  }
+
+  removeOnSetPlayerMovementMode(callback: (ctx: ReducerEventContext, identity: Identity, mode: MovementMode) => void) {
+    this.connection.offReducer("set_player_movement_mode", callback);
+  }
+
+  setSceneSpawnPoint(sceneVersion: number, position: DbVector3) {
+    const __args = { sceneVersion, position };
+    let __writer = new BinaryWriter(1024);
+    SetSceneSpawnPoint.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_scene_spawn_point", __argsBuffer, this.setCallReducerFlags.setSceneSpawnPointFlags);
+  }
+
+  onSetSceneSpawnPoint(callback: (ctx: ReducerEventContext, sceneVersion: number, position: DbVector3) => void) {
+    this.connection.onReducer("set_scene_spawn_point", callback);
+  }
+
+  removeOnSetSceneSpawnPoint(callback: (ctx: ReducerEventContext, sceneVersion: number, position: DbVector3) => void) {
+    this.connection.offReducer("set_scene_spawn_point", callback);
+  }
+
+  setWorldPlayerCollisions(enabled: boolean) {
+    const __args = { enabled };
+    let __writer = new BinaryWriter(1024);
+    SetWorldPlayerCollisions.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_world_player_collisions", __argsBuffer, this.setCallReducerFlags.setWorldPlayerCollisionsFlags);
+  }
+
+  onSetWorldPlayerCollisions(callback: (ctx: ReducerEventContext, enabled: boolean) => void) {
+    this.connection.onReducer("set_world_player_collisions", callback);
+  }
+
+  removeOnSetWorldPlayerCollisions(callback: (ctx: ReducerEventContext, enabled: boolean) => void) {
+    this.connection.offReducer("set_world_player_collisions", callback);
+  }
+
+  shapeCast(requestId: number, origin: DbVector3, direction: DbVector3, radius: number, maxDistance: number) {
+    const __args = { requestId, origin, direction, radius, maxDistance };
+    let __writer = new BinaryWriter(1024);
+    ShapeCast.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("shape_cast", __argsBuffer, this.setCallReducerFlags.shapeCastFlags);
+  }
+
+  onShapeCast(callback: (ctx: ReducerEventContext, requestId: number, origin: DbVector3, direction: DbVector3, radius: number, maxDistance: number) => void) {
+    this.connection.onReducer("shape_cast", callback);
+  }
+
+  removeOnShapeCast(callback: (ctx: ReducerEventContext, requestId: number, origin: DbVector3, direction: DbVector3, radius: number, maxDistance: number) => void) {
+    this.connection.offReducer("shape_cast", callback);
+  }
+
+  sit(seatId: number) {
+    const __args = { seatId };
+    let __writer = new BinaryWriter(1024);
+    Sit.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("sit", __argsBuffer, this.setCallReducerFlags.sitFlags);
+  }
+
+  onSit(callback: (ctx: ReducerEventContext, seatId: number) => void) {
+    this.connection.onReducer("sit", callback);
+  }
+
+  removeOnSit(callback: (ctx: ReducerEventContext, seatId: number) => void) {
+    this.connection.offReducer("sit", callback);
+  }
+
+  snapshotPhysics() {
+    const __args = {};
+    let __writer = new BinaryWriter(1024);
+    SnapshotPhysics.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("snapshot_physics", __argsBuffer, this.setCallReducerFlags.snapshotPhysicsFlags);
+  }
+
+  onSnapshotPhysics(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("snapshot_physics", callback);
+  }
+
+  removeOnSnapshotPhysics(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("snapshot_physics", callback);
+  }
+
+  stand() {
+    const __args = {};
+    let __writer = new BinaryWriter(1024);
+    Stand.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("stand", __argsBuffer, this.setCallReducerFlags.standFlags);
+  }
+
+  onStand(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("stand", callback);
+  }
+
+  removeOnStand(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("stand", callback);
+  }
+
+  tick(schedule: TickSchedule) {
+    const __args = { schedule };
+    let __writer = new BinaryWriter(1024);
+    Tick.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("tick", __argsBuffer, this.setCallReducerFlags.tickFlags);
+  }
+
+  onTick(callback: (ctx: ReducerEventContext, schedule: TickSchedule) => void) {
+    this.connection.onReducer("tick", callback);
+  }
+
+  removeOnTick(callback: (ctx: ReducerEventContext, schedule: TickSchedule) => void) {
+    this.connection.offReducer("tick", callback);
+  }
+
+  unbanPlayer(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    UnbanPlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unban_player", __argsBuffer, this.setCallReducerFlags.unbanPlayerFlags);
+  }
+
+  onUnbanPlayer(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("unban_player", callback);
+  }
+
+  removeOnUnbanPlayer(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("unban_player", callback);
+  }
+
+  unblockUser(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    UnblockUser.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unblock_user", __argsBuffer, this.setCallReducerFlags.unblockUserFlags);
+  }
+
+  onUnblockUser(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("unblock_user", callback);
+  }
+
+  removeOnUnblockUser(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("unblock_user", callback);
+  }
+
+  unmutePlayer(identity: Identity) {
+    const __args = { identity };
+    let __writer = new BinaryWriter(1024);
+    UnmutePlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unmute_player", __argsBuffer, this.setCallReducerFlags.unmutePlayerFlags);
+  }
+
+  onUnmutePlayer(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.onReducer("unmute_player", callback);
+  }
+
+  removeOnUnmutePlayer(callback: (ctx: ReducerEventContext, identity: Identity) => void) {
+    this.connection.offReducer("unmute_player", callback);
+  }
+
+  updatePlayerInput(input: InputState, rotation: number, pitch: number, roll: number) {
+    const __args = { input, rotation, pitch, roll };
+    let __writer = new BinaryWriter(1024);
+    UpdatePlayerInput.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_player_input", __argsBuffer, this.setCallReducerFlags.updatePlayerInputFlags);
+  }
+
+  onUpdatePlayerInput(callback: (ctx: ReducerEventContext, input: InputState, rotation: number, pitch: number, roll: number) => void) {
+    this.connection.onReducer("update_player_input", callback);
+  }
+
+  removeOnUpdatePlayerInput(callback: (ctx: ReducerEventContext, input: InputState, rotation: number, pitch: number, roll: number) => void) {
+    this.connection.offReducer("update_player_input", callback);
+  }
+
+  updateTrackedPose(head: QuantizedPose, leftHand: QuantizedPose | undefined, rightHand: QuantizedPose | undefined, fingerCurls: Uint8Array | undefined) {
+    const __args = { head, leftHand, rightHand, fingerCurls };
+    let __writer = new BinaryWriter(1024);
+    UpdateTrackedPose.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_tracked_pose", __argsBuffer, this.setCallReducerFlags.updateTrackedPoseFlags);
+  }
+
+  onUpdateTrackedPose(callback: (ctx: ReducerEventContext, head: QuantizedPose, leftHand: QuantizedPose | undefined, rightHand: QuantizedPose | undefined, fingerCurls: Uint8Array | undefined) => void) {
+    this.connection.onReducer("update_tracked_pose", callback);
+  }
+
+  removeOnUpdateTrackedPose(callback: (ctx: ReducerEventContext, head: QuantizedPose, leftHand: QuantizedPose | undefined, rightHand: QuantizedPose | undefined, fingerCurls: Uint8Array | undefined) => void) {
+    this.connection.offReducer("update_tracked_pose", callback);
+  }
+
+  uploadBody(points: DbVector3[], indices: DbVector3[], name: string, sceneVersion: number, material: SurfaceMaterial, volume: VolumeType | undefined) {
+    const __args = { points, indices, name, sceneVersion, material, volume };
+    let __writer = new BinaryWriter(1024);
+    UploadBody.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("upload_body", __argsBuffer, this.setCallReducerFlags.uploadBodyFlags);
+  }
+
+  onUploadBody(callback: (ctx: ReducerEventContext, points: DbVector3[], indices: DbVector3[], name: string, sceneVersion: number, material: SurfaceMaterial, volume: VolumeType | undefined) => void) {
+    this.connection.onReducer("upload_body", callback);
+  }
+
+  removeOnUploadBody(callback: (ctx: ReducerEventContext, points: DbVector3[], indices: DbVector3[], name: string, sceneVersion: number, material: SurfaceMaterial, volume: VolumeType | undefined) => void) {
+    this.connection.offReducer("upload_body", callback);
+  }
+
+}
+
+export class SetReducerFlags {
+  acceptFriendRequestFlags: CallReducerFlags = 'FullUpdate';
+  acceptFriendRequest(flags: CallReducerFlags) {
+    this.acceptFriendRequestFlags = flags;
+  }
+
+  banPlayerFlags: CallReducerFlags = 'FullUpdate';
+  banPlayer(flags: CallReducerFlags) {
+    this.banPlayerFlags = flags;
+  }
+
+  blockUserFlags: CallReducerFlags = 'FullUpdate';
+  blockUser(flags: CallReducerFlags) {
+    this.blockUserFlags = flags;
+  }
+
+  checkIdlePlayersFlags: CallReducerFlags = 'FullUpdate';
+  checkIdlePlayers(flags: CallReducerFlags) {
+    this.checkIdlePlayersFlags = flags;
+  }
+
+  clearInputRecordingFlags: CallReducerFlags = 'FullUpdate';
+  clearInputRecording(flags: CallReducerFlags) {
+    this.clearInputRecordingFlags = flags;
+  }
+
+  clearTrackedPoseFlags: CallReducerFlags = 'FullUpdate';
+  clearTrackedPose(flags: CallReducerFlags) {
+    this.clearTrackedPoseFlags = flags;
+  }
+
+  createPlatformFlags: CallReducerFlags = 'FullUpdate';
+  createPlatform(flags: CallReducerFlags) {
+    this.createPlatformFlags = flags;
+  }
+
+  createPortalFlags: CallReducerFlags = 'FullUpdate';
+  createPortal(flags: CallReducerFlags) {
+    this.createPortalFlags = flags;
+  }
+
+  createRoomFlags: CallReducerFlags = 'FullUpdate';
+  createRoom(flags: CallReducerFlags) {
+    this.createRoomFlags = flags;
+  }
+
+  createSeatFlags: CallReducerFlags = 'FullUpdate';
+  createSeat(flags: CallReducerFlags) {
+    this.createSeatFlags = flags;
+  }
+
+  deleteRoomFlags: CallReducerFlags = 'FullUpdate';
+  deleteRoom(flags: CallReducerFlags) {
+    this.deleteRoomFlags = flags;
+  }
+
+  getFriendPresenceFlags: CallReducerFlags = 'FullUpdate';
+  getFriendPresence(flags: CallReducerFlags) {
+    this.getFriendPresenceFlags = flags;
+  }
+
+  getLeaderboardFlags: CallReducerFlags = 'FullUpdate';
+  getLeaderboard(flags: CallReducerFlags) {
+    this.getLeaderboardFlags = flags;
+  }
+
+  grantAdminFlags: CallReducerFlags = 'FullUpdate';
+  grantAdmin(flags: CallReducerFlags) {
+    this.grantAdminFlags = flags;
+  }
+
+  inviteToInstanceFlags: CallReducerFlags = 'FullUpdate';
+  inviteToInstance(flags: CallReducerFlags) {
+    this.inviteToInstanceFlags = flags;
+  }
+
+  joinFriendFlags: CallReducerFlags = 'FullUpdate';
+  joinFriend(flags: CallReducerFlags) {
+    this.joinFriendFlags = flags;
+  }
+
+  joinInstanceFlags: CallReducerFlags = 'FullUpdate';
+  joinInstance(flags: CallReducerFlags) {
+    this.joinInstanceFlags = flags;
+  }
+
+  kickFlags: CallReducerFlags = 'FullUpdate';
+  kick(flags: CallReducerFlags) {
+    this.kickFlags = flags;
+  }
+
+  mutePlayerFlags: CallReducerFlags = 'FullUpdate';
+  mutePlayer(flags: CallReducerFlags) {
+    this.mutePlayerFlags = flags;
+  }
+
+  playEmoteFlags: CallReducerFlags = 'FullUpdate';
+  playEmote(flags: CallReducerFlags) {
+    this.playEmoteFlags = flags;
+  }
+
+  pointQueryFlags: CallReducerFlags = 'FullUpdate';
+  pointQuery(flags: CallReducerFlags) {
+    this.pointQueryFlags = flags;
+  }
+
+  purgeLoggedOutPlayersFlags: CallReducerFlags = 'FullUpdate';
+  purgeLoggedOutPlayers(flags: CallReducerFlags) {
+    this.purgeLoggedOutPlayersFlags = flags;
+  }
+
+  raycastFlags: CallReducerFlags = 'FullUpdate';
+  raycast(flags: CallReducerFlags) {
+    this.raycastFlags = flags;
+  }
+
+  removeEmoteFlags: CallReducerFlags = 'FullUpdate';
+  removeEmote(flags: CallReducerFlags) {
+    this.removeEmoteFlags = flags;
+  }
+
+  removeExpiredMutesFlags: CallReducerFlags = 'FullUpdate';
+  removeExpiredMutes(flags: CallReducerFlags) {
+    this.removeExpiredMutesFlags = flags;
+  }
+
+  removeFriendFlags: CallReducerFlags = 'FullUpdate';
+  removeFriend(flags: CallReducerFlags) {
+    this.removeFriendFlags = flags;
+  }
+
+  removePlatformFlags: CallReducerFlags = 'FullUpdate';
+  removePlatform(flags: CallReducerFlags) {
+    this.removePlatformFlags = flags;
+  }
+
+  removePortalFlags: CallReducerFlags = 'FullUpdate';
+  removePortal(flags: CallReducerFlags) {
+    this.removePortalFlags = flags;
+  }
+
+  removeSeatFlags: CallReducerFlags = 'FullUpdate';
+  removeSeat(flags: CallReducerFlags) {
+    this.removeSeatFlags = flags;
+  }
+
+  revokeAdminFlags: CallReducerFlags = 'FullUpdate';
+  revokeAdmin(flags: CallReducerFlags) {
+    this.revokeAdminFlags = flags;
+  }
+
+  revokeInviteFlags: CallReducerFlags = 'FullUpdate';
+  revokeInvite(flags: CallReducerFlags) {
+    this.revokeInviteFlags = flags;
+  }
+
+  sendFriendRequestFlags: CallReducerFlags = 'FullUpdate';
+  sendFriendRequest(flags: CallReducerFlags) {
+    this.sendFriendRequestFlags = flags;
+  }
+
+  setColliderMaterialFlags: CallReducerFlags = 'FullUpdate';
+  setColliderMaterial(flags: CallReducerFlags) {
+    this.setColliderMaterialFlags = flags;
+  }
+
+  setColliderVolumeFlags: CallReducerFlags = 'FullUpdate';
+  setColliderVolume(flags: CallReducerFlags) {
+    this.setColliderVolumeFlags = flags;
+  }
+
+  setDefaultSceneFlags: CallReducerFlags = 'FullUpdate';
+  setDefaultScene(flags: CallReducerFlags) {
+    this.setDefaultSceneFlags = flags;
+  }
+
+  setEmoteFlags: CallReducerFlags = 'FullUpdate';
+  setEmote(flags: CallReducerFlags) {
+    this.setEmoteFlags = flags;
+  }
+
+  setIdleTimeoutsFlags: CallReducerFlags = 'FullUpdate';
+  setIdleTimeouts(flags: CallReducerFlags) {
+    this.setIdleTimeoutsFlags = flags;
+  }
+
+  setInputRecordingFlags: CallReducerFlags = 'FullUpdate';
+  setInputRecording(flags: CallReducerFlags) {
+    this.setInputRecordingFlags = flags;
+  }
+
+  setInstanceVisibilityFlags: CallReducerFlags = 'FullUpdate';
+  setInstanceVisibility(flags: CallReducerFlags) {
+    this.setInstanceVisibilityFlags = flags;
+  }
+
+  setLoggedOutRetentionFlags: CallReducerFlags = 'FullUpdate';
+  setLoggedOutRetention(flags: CallReducerFlags) {
+    this.setLoggedOutRetentionFlags = flags;
+  }
+
+  setPersistentProfileFlags: CallReducerFlags = 'FullUpdate';
+  setPersistentProfile(flags: CallReducerFlags) {
+    this.setPersistentProfileFlags = flags;
+  }
+
+  setPersonalSpaceFlags: CallReducerFlags = 'FullUpdate';
+  setPersonalSpace(flags: CallReducerFlags) {
+    this.setPersonalSpaceFlags = flags;
+  }
+
+  setPlayerCollisionsFlags: CallReducerFlags = 'FullUpdate';
+  setPlayerCollisions(flags: CallReducerFlags) {
+    this.setPlayerCollisionsFlags = flags;
+  }
+
+  setPlayerMovementModeFlags: CallReducerFlags = 'FullUpdate';
+  setPlayerMovementMode(flags: CallReducerFlags) {
+    this.setPlayerMovementModeFlags = flags;
+  }
+
+  setSceneSpawnPointFlags: CallReducerFlags = 'FullUpdate';
+  setSceneSpawnPoint(flags: CallReducerFlags) {
+    this.setSceneSpawnPointFlags = flags;
+  }
+
+  setWorldPlayerCollisionsFlags: CallReducerFlags = 'FullUpdate';
+  setWorldPlayerCollisions(flags: CallReducerFlags) {
+    this.setWorldPlayerCollisionsFlags = flags;
+  }
+
+  shapeCastFlags: CallReducerFlags = 'FullUpdate';
+  shapeCast(flags: CallReducerFlags) {
+    this.shapeCastFlags = flags;
+  }
+
+  sitFlags: CallReducerFlags = 'FullUpdate';
+  sit(flags: CallReducerFlags) {
+    this.sitFlags = flags;
+  }
+
+  snapshotPhysicsFlags: CallReducerFlags = 'FullUpdate';
+  snapshotPhysics(flags: CallReducerFlags) {
+    this.snapshotPhysicsFlags = flags;
+  }
+
+  standFlags: CallReducerFlags = 'FullUpdate';
+  stand(flags: CallReducerFlags) {
+    this.standFlags = flags;
+  }
+
+  tickFlags: CallReducerFlags = 'FullUpdate';
+  tick(flags: CallReducerFlags) {
+    this.tickFlags = flags;
+  }
+
+  unbanPlayerFlags: CallReducerFlags = 'FullUpdate';
+  unbanPlayer(flags: CallReducerFlags) {
+    this.unbanPlayerFlags = flags;
+  }
+
+  unblockUserFlags: CallReducerFlags = 'FullUpdate';
+  unblockUser(flags: CallReducerFlags) {
+    this.unblockUserFlags = flags;
+  }
+
+  unmutePlayerFlags: CallReducerFlags = 'FullUpdate';
+  unmutePlayer(flags: CallReducerFlags) {
+    this.unmutePlayerFlags = flags;
+  }
+
+  updatePlayerInputFlags: CallReducerFlags = 'FullUpdate';
+  updatePlayerInput(flags: CallReducerFlags) {
+    this.updatePlayerInputFlags = flags;
+  }
+
+  updateTrackedPoseFlags: CallReducerFlags = 'FullUpdate';
+  updateTrackedPose(flags: CallReducerFlags) {
+    this.updateTrackedPoseFlags = flags;
+  }
+
+  uploadBodyFlags: CallReducerFlags = 'FullUpdate';
+  uploadBody(flags: CallReducerFlags) {
+    this.uploadBodyFlags = flags;
+  }
+
+}
+
+export class RemoteTables {
+  constructor(private connection: DbConnectionImpl) {}
+
+  get admin(): AdminTableHandle {
+    return new AdminTableHandle(this.connection.clientCache.getOrCreateTable<Admin>(REMOTE_MODULE.tables.admin));
+  }
+
+  get auditLog(): AuditLogTableHandle {
+    return new AuditLogTableHandle(this.connection.clientCache.getOrCreateTable<AuditLog>(REMOTE_MODULE.tables.audit_log));
+  }
+
+  get ban(): BanTableHandle {
+    return new BanTableHandle(this.connection.clientCache.getOrCreateTable<Ban>(REMOTE_MODULE.tables.ban));
+  }
+
+  get block(): BlockTableHandle {
+    return new BlockTableHandle(this.connection.clientCache.getOrCreateTable<Block>(REMOTE_MODULE.tables.block));
+  }
+
+  get cleanupSchedule(): CleanupScheduleTableHandle {
+    return new CleanupScheduleTableHandle(this.connection.clientCache.getOrCreateTable<CleanupSchedule>(REMOTE_MODULE.tables.cleanup_schedule));
+  }
+
+  get clientConnection(): ClientConnectionTableHandle {
+    return new ClientConnectionTableHandle(this.connection.clientCache.getOrCreateTable<ClientConnection>(REMOTE_MODULE.tables.client_connection));
+  }
+
+  get collider(): ColliderTableHandle {
+    return new ColliderTableHandle(this.connection.clientCache.getOrCreateTable<Collider>(REMOTE_MODULE.tables.collider));
+  }
+
+  get emoteCatalog(): EmoteCatalogTableHandle {
+    return new EmoteCatalogTableHandle(this.connection.clientCache.getOrCreateTable<Emote>(REMOTE_MODULE.tables.emote_catalog));
+  }
+
+  get friendPresenceResult(): FriendPresenceResultTableHandle {
+    return new FriendPresenceResultTableHandle(this.connection.clientCache.getOrCreateTable<FriendPresenceResult>(REMOTE_MODULE.tables.friend_presence_result));
+  }
+
+  get friendship(): FriendshipTableHandle {
+    return new FriendshipTableHandle(this.connection.clientCache.getOrCreateTable<Friendship>(REMOTE_MODULE.tables.friendship));
+  }
+
+  get idleCheckSchedule(): IdleCheckScheduleTableHandle {
+    return new IdleCheckScheduleTableHandle(this.connection.clientCache.getOrCreateTable<IdleCheckSchedule>(REMOTE_MODULE.tables.idle_check_schedule));
+  }
+
+  get inputRateLimit(): InputRateLimitTableHandle {
+    return new InputRateLimitTableHandle(this.connection.clientCache.getOrCreateTable<InputRateLimit>(REMOTE_MODULE.tables.input_rate_limit));
+  }
+
+  get instance(): InstanceTableHandle {
+    return new InstanceTableHandle(this.connection.clientCache.getOrCreateTable<Instance>(REMOTE_MODULE.tables.instance));
+  }
+
+  get instanceInvite(): InstanceInviteTableHandle {
+    return new InstanceInviteTableHandle(this.connection.clientCache.getOrCreateTable<InstanceInvite>(REMOTE_MODULE.tables.instance_invite));
+  }
+
+  get leaderboardResult(): LeaderboardResultTableHandle {
+    return new LeaderboardResultTableHandle(this.connection.clientCache.getOrCreateTable<LeaderboardResult>(REMOTE_MODULE.tables.leaderboard_result));
+  }
+
+  get loggedOutPlayer(): LoggedOutPlayerTableHandle {
+    return new LoggedOutPlayerTableHandle(this.connection.clientCache.getOrCreateTable<Player>(REMOTE_MODULE.tables.logged_out_player));
+  }
+
+  get moderationFlag(): ModerationFlagTableHandle {
+    return new ModerationFlagTableHandle(this.connection.clientCache.getOrCreateTable<ModerationFlag>(REMOTE_MODULE.tables.moderation_flag));
+  }
+
+  get mute(): MuteTableHandle {
+    return new MuteTableHandle(this.connection.clientCache.getOrCreateTable<Mute>(REMOTE_MODULE.tables.mute));
+  }
+
+  get muteExpirySchedule(): MuteExpiryScheduleTableHandle {
+    return new MuteExpiryScheduleTableHandle(this.connection.clientCache.getOrCreateTable<MuteExpirySchedule>(REMOTE_MODULE.tables.mute_expiry_schedule));
+  }
+
+  get physicsSnapshot(): PhysicsSnapshotTableHandle {
+    return new PhysicsSnapshotTableHandle(this.connection.clientCache.getOrCreateTable<PhysicsSnapshot>(REMOTE_MODULE.tables.physics_snapshot));
+  }
+
+  get platform(): PlatformTableHandle {
+    return new PlatformTableHandle(this.connection.clientCache.getOrCreateTable<Platform>(REMOTE_MODULE.tables.platform));
+  }
+
+  get player(): PlayerTableHandle {
+    return new PlayerTableHandle(this.connection.clientCache.getOrCreateTable<Player>(REMOTE_MODULE.tables.player));
+  }
+
+  get playerStats(): PlayerStatsTableHandle {
+    return new PlayerStatsTableHandle(this.connection.clientCache.getOrCreateTable<PlayerStats>(REMOTE_MODULE.tables.player_stats));
+  }
+
+  get portal(): PortalTableHandle {
+    return new PortalTableHandle(this.connection.clientCache.getOrCreateTable<Portal>(REMOTE_MODULE.tables.portal));
+  }
+
+  get portalCooldown(): PortalCooldownTableHandle {
+    return new PortalCooldownTableHandle(this.connection.clientCache.getOrCreateTable<PortalCooldown>(REMOTE_MODULE.tables.portal_cooldown));
+  }
+
+  get queryResult(): QueryResultTableHandle {
+    return new QueryResultTableHandle(this.connection.clientCache.getOrCreateTable<QueryResult>(REMOTE_MODULE.tables.query_result));
+  }
+
+  get recordedInput(): RecordedInputTableHandle {
+    return new RecordedInputTableHandle(this.connection.clientCache.getOrCreateTable<RecordedInput>(REMOTE_MODULE.tables.recorded_input));
+  }
+
+  get sceneSpawn(): SceneSpawnTableHandle {
+    return new SceneSpawnTableHandle(this.connection.clientCache.getOrCreateTable<SceneSpawn>(REMOTE_MODULE.tables.scene_spawn));
+  }
+
+  get seat(): SeatTableHandle {
+    return new SeatTableHandle(this.connection.clientCache.getOrCreateTable<Seat>(REMOTE_MODULE.tables.seat));
+  }
+
+  get session(): SessionTableHandle {
+    return new SessionTableHandle(this.connection.clientCache.getOrCreateTable<Session>(REMOTE_MODULE.tables.session));
+  }
+
+  get simulationClock(): SimulationClockTableHandle {
+    return new SimulationClockTableHandle(this.connection.clientCache.getOrCreateTable<SimulationClock>(REMOTE_MODULE.tables.simulation_clock));
+  }
+
+  get tickSchedule(): TickScheduleTableHandle {
+    return new TickScheduleTableHandle(this.connection.clientCache.getOrCreateTable<TickSchedule>(REMOTE_MODULE.tables.tick_schedule));
+  }
+
+  get trackedPose(): TrackedPoseTableHandle {
+    return new TrackedPoseTableHandle(this.connection.clientCache.getOrCreateTable<TrackedPose>(REMOTE_MODULE.tables.tracked_pose));
+  }
+
+  get worldSettings(): WorldSettingsTableHandle {
+    return new WorldSettingsTableHandle(this.connection.clientCache.getOrCreateTable<WorldSettings>(REMOTE_MODULE.tables.world_settings));
   }
 }
 
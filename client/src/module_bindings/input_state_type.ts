@@ -36,6 +36,7 @@ export type InputState = {
   left: boolean,
   right: boolean,
   jump: boolean,
+  crouch: boolean,
   isPointerLocked: boolean,
 };
 
@@ -54,6 +55,7 @@ export namespace InputState {
       new ProductTypeElement("left", AlgebraicType.createBoolType()),
       new ProductTypeElement("right", AlgebraicType.createBoolType()),
       new ProductTypeElement("jump", AlgebraicType.createBoolType()),
+      new ProductTypeElement("crouch", AlgebraicType.createBoolType()),
       new ProductTypeElement("isPointerLocked", AlgebraicType.createBoolType()),
     ]);
   }
@@ -31,8 +31,11 @@ import {
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
 import { Player } from "./player_type";
+import { DbQuaternion as __DbQuaternion } from "./db_quaternion_type";
 import { DbVector3 as __DbVector3 } from "./db_vector_3_type";
 import { InputState as __InputState } from "./input_state_type";
+import { MovementMode as __MovementMode } from "./movement_mode_type";
+import { SurfaceType as __SurfaceType } from "./surface_type_type";
 
 import { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
 
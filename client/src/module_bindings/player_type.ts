@@ -30,8 +30,11 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+import { DbQuaternion as __DbQuaternion } from "./db_quaternion_type";
 import { DbVector3 as __DbVector3 } from "./db_vector_3_type";
 import { InputState as __InputState } from "./input_state_type";
+import { MovementMode as __MovementMode } from "./movement_mode_type";
+import { SurfaceType as __SurfaceType } from "./surface_type_type";
 
 export type Player = {
   identity: Identity,
@@ -40,8 +43,20 @@ export type Player = {
   hexColor: string | undefined,
   position: __DbVector3,
   rotationYaw: number,
+  lookRotation: __DbQuaternion,
   animationState: string | undefined,
+  emoteExpiresAt: Timestamp | undefined,
   input: __InputState,
+  groundSurface: __SurfaceType | undefined,
+  collideWithPlayers: boolean,
+  movementMode: __MovementMode,
+  personalSpaceRadius: number | undefined,
+  seatId: number | undefined,
+  lastInputAt: Timestamp,
+  afk: boolean,
+  persistentProfile: boolean,
+  loggedOutAt: Timestamp | undefined,
+  instanceId: number,
 };
 
 /**
@@ -60,8 +75,20 @@ export namespace Player {
       new ProductTypeElement("hexColor", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
       new ProductTypeElement("position", __DbVector3.getTypeScriptAlgebraicType()),
       new ProductTypeElement("rotationYaw", AlgebraicType.createF32Type()),
+      new ProductTypeElement("lookRotation", __DbQuaternion.getTypeScriptAlgebraicType()),
       new ProductTypeElement("animationState", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("emoteExpiresAt", AlgebraicType.createOptionType(AlgebraicType.createTimestampType())),
       new ProductTypeElement("input", __InputState.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("groundSurface", AlgebraicType.createOptionType(__SurfaceType.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("collideWithPlayers", AlgebraicType.createBoolType()),
+      new ProductTypeElement("movementMode", __MovementMode.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("personalSpaceRadius", AlgebraicType.createOptionType(AlgebraicType.createF32Type())),
+      new ProductTypeElement("seatId", AlgebraicType.createOptionType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("lastInputAt", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("afk", AlgebraicType.createBoolType()),
+      new ProductTypeElement("persistentProfile", AlgebraicType.createBoolType()),
+      new ProductTypeElement("loggedOutAt", AlgebraicType.createOptionType(AlgebraicType.createTimestampType())),
+      new ProductTypeElement("instanceId", AlgebraicType.createU32Type()),
     ]);
   }
 
@@ -36,6 +36,8 @@ import { InputState as __InputState } from "./input_state_type";
 export type UpdatePlayerInput = {
   input: __InputState,
   rotation: number,
+  pitch: number,
+  roll: number,
 };
 
 /**
@@ -50,6 +52,8 @@ export namespace UpdatePlayerInput {
     return AlgebraicType.createProductType([
       new ProductTypeElement("input", __InputState.getTypeScriptAlgebraicType()),
       new ProductTypeElement("rotation", AlgebraicType.createF32Type()),
+      new ProductTypeElement("pitch", AlgebraicType.createF32Type()),
+      new ProductTypeElement("roll", AlgebraicType.createF32Type()),
     ]);
   }
 
@@ -32,11 +32,16 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 
 import { DbVector3 as __DbVector3 } from "./db_vector_3_type";
+import { SurfaceMaterial as __SurfaceMaterial } from "./surface_material_type";
+import { VolumeType as __VolumeType } from "./volume_type_type";
 
 export type UploadBody = {
   points: __DbVector3[],
   indices: __DbVector3[],
   name: string,
+  sceneVersion: number,
+  material: __SurfaceMaterial,
+  volume: __VolumeType | undefined,
 };
 
 /**
@@ -52,6 +57,9 @@ export namespace UploadBody {
       new ProductTypeElement("points", AlgebraicType.createArrayType(__DbVector3.getTypeScriptAlgebraicType())),
       new ProductTypeElement("indices", AlgebraicType.createArrayType(__DbVector3.getTypeScriptAlgebraicType())),
       new ProductTypeElement("name", AlgebraicType.createStringType()),
+      new ProductTypeElement("sceneVersion", AlgebraicType.createU32Type()),
+      new ProductTypeElement("material", __SurfaceMaterial.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("volume", AlgebraicType.createOptionType(__VolumeType.getTypeScriptAlgebraicType())),
     ]);
   }
 
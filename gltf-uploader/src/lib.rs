@@ -1,18 +1,26 @@
+mod math;
 mod module_bindings;
-mod scene;
 
 use module_bindings::*;
+use shared::scene;
 use spacetimedb_sdk::*;
 
 const HOST: &str = "wss://maincloud.spacetimedb.com";
 // const HOST: &str = "ws://localhost:3000";
 const MODULE_NAME: &str = "vrchatdemo-gerbuuun";
+// Uploading bodies and creating platforms is limited to admins, so the uploader connects with the token of an admin
+const TOKEN_VAR: &str = "SPACETIME_TOKEN";
 const SCENE_PATH: &str = "/Users/gerbuuun/Development/github.com/Gerbuuun/vrchatdemo/client/public/models/forest_scene/scene.glb";
+// Scene version the colliders are uploaded for, bump it when uploading a new version of the scene
+const SCENE_VERSION: u32 = 0;
+// Scale applied to the whole scene, matching the scale the client renders it at
+const SCENE_SCALE: f32 = 4.0;
 
 fn connect_to_db() -> DbConnection {
     DbConnection::builder()
         .with_uri(HOST)
         .with_module_name(MODULE_NAME)
+        .with_token(std::env::var(TOKEN_VAR).ok())
         .on_connect(on_connect)
         .on_connect_error(on_connect_error)
         .on_disconnect(on_disconnect)
@@ -40,41 +48,70 @@ fn on_disconnect(_ctx: &ErrorContext, err: Option<Error>) {
 }
 
 pub fn main() {
+    // Show the progress logged while loading the scene, unless RUST_LOG says otherwise
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
     let ctx = connect_to_db();
-    ctx.reducers
-        .on_upload_body(|_ctx, _points, _indices, _name| {
+    ctx.reducers.on_upload_body(
+        |_ctx, _points, _indices, _name, _scene_version, _material, _volume| {
             println!("Uploaded {} with {} points", _name, _points.len());
-        });
+        },
+    );
+
+    ctx.reducers.on_create_platform(|ctx, name, _, keyframes| {
+        if let Status::Failed(error) = &ctx.event.status {
+            eprintln!("Failed to create platform {}: {}", name, error);
+        } else {
+            println!(
+                "Created platform {} with {} keyframes",
+                name,
+                keyframes.len()
+            );
+        }
+    });
 
     ctx.run_threaded();
 
     let mut count = 0;
-    for (point_array, indices, name) in scene::load_scene_mesh(SCENE_PATH) {
+    for mesh in scene::load_scene_mesh(SCENE_PATH, SCENE_SCALE) {
         ctx.reducers
             .upload_body(
-                point_array
-                    .iter()
-                    .map(|p| DbVector3 {
-                        x: p.x,
-                        y: p.y,
-                        z: p.z,
-                    })
+                mesh.positions
+                    .into_iter()
+                    .map(|point| shared::math::DbVector3::from(point).into())
                     .collect(),
-                indices
-                    .iter()
-                    .map(|i| DbVector3 {
-                        x: i[0] as f32,
-                        y: i[1] as f32,
-                        z: i[2] as f32,
-                    })
+                mesh.indices
+                    .into_iter()
+                    .map(|indices| shared::math::DbVector3::from(indices).into())
                     .collect(),
-                name,
+                mesh.name,
+                SCENE_VERSION,
+                mesh.material,
+                mesh.volume,
             )
             .expect("Failed to upload body");
 
         count += 1;
     }
 
+    // Turn every node with a translation animation into a moving platform
+    for animation in scene::load_scene_animations(SCENE_PATH, SCENE_SCALE) {
+        ctx.reducers
+            .create_platform(
+                animation.name,
+                SCENE_VERSION,
+                animation
+                    .keyframes
+                    .into_iter()
+                    .map(|keyframe| PlatformKeyframe {
+                        time: keyframe.time,
+                        translation: shared::math::DbVector3::from(keyframe.translation).into(),
+                    })
+                    .collect(),
+            )
+            .expect("Failed to create platform");
+    }
+
     loop {
         std::thread::sleep(std::time::Duration::from_secs(1));
         println!("Uploaded {} bodies", count);
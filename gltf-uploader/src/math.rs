@@ -0,0 +1,14 @@
+use crate::module_bindings::DbVector3;
+use shared::math;
+
+// The generated vector has the same layout as `shared::math::DbVector3`, which owns every other conversion
+
+impl From<math::DbVector3> for DbVector3 {
+    fn from(vector: math::DbVector3) -> Self {
+        Self {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+}
@@ -12,6 +12,7 @@ pub struct InputState {
     pub left: bool,
     pub right: bool,
     pub jump: bool,
+    pub crouch: bool,
     pub is_pointer_locked: bool,
 }
 
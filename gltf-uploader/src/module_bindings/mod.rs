@@ -7,27 +7,38 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 pub mod collider_table;
 pub mod collider_type;
 pub mod connect_reducer;
+pub mod create_platform_reducer;
 pub mod db_vector_3_type;
 pub mod disconnect_reducer;
 pub mod input_state_type;
 pub mod logged_out_player_table;
+pub mod platform_keyframe_type;
 pub mod player_table;
 pub mod player_type;
+pub mod surface_material_type;
+pub mod surface_type_type;
 pub mod tick_reducer;
 pub mod tick_schedule_table;
 pub mod tick_schedule_type;
 pub mod update_player_input_reducer;
 pub mod upload_body_reducer;
+pub mod volume_type_type;
 
 pub use collider_table::*;
 pub use collider_type::Collider;
 pub use connect_reducer::{connect, set_flags_for_connect, ConnectCallbackId};
+pub use create_platform_reducer::{
+    create_platform, set_flags_for_create_platform, CreatePlatformCallbackId,
+};
 pub use db_vector_3_type::DbVector3;
 pub use disconnect_reducer::{disconnect, set_flags_for_disconnect, DisconnectCallbackId};
 pub use input_state_type::InputState;
 pub use logged_out_player_table::*;
+pub use platform_keyframe_type::PlatformKeyframe;
 pub use player_table::*;
 pub use player_type::Player;
+pub use surface_material_type::SurfaceMaterial;
+pub use surface_type_type::SurfaceType;
 pub use tick_reducer::{set_flags_for_tick, tick, TickCallbackId};
 pub use tick_schedule_table::*;
 pub use tick_schedule_type::TickSchedule;
@@ -35,6 +46,7 @@ pub use update_player_input_reducer::{
     set_flags_for_update_player_input, update_player_input, UpdatePlayerInputCallbackId,
 };
 pub use upload_body_reducer::{set_flags_for_upload_body, upload_body, UploadBodyCallbackId};
+pub use volume_type_type::VolumeType;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -45,6 +57,11 @@ pub use upload_body_reducer::{set_flags_for_upload_body, upload_body, UploadBody
 
 pub enum Reducer {
     Connect,
+    CreatePlatform {
+        collider_name: String,
+        scene_version: u32,
+        keyframes: Vec<PlatformKeyframe>,
+    },
     Disconnect,
     Tick {
         schedule: TickSchedule,
@@ -52,11 +69,16 @@ pub enum Reducer {
     UpdatePlayerInput {
         input: InputState,
         rotation: f32,
+        pitch: f32,
+        roll: f32,
     },
     UploadBody {
         points: Vec<DbVector3>,
         indices: Vec<DbVector3>,
         name: String,
+        scene_version: u32,
+        material: SurfaceMaterial,
+        volume: Option<VolumeType>,
     },
 }
 
@@ -68,6 +90,7 @@ impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
             Reducer::Connect => "connect",
+            Reducer::CreatePlatform { .. } => "create_platform",
             Reducer::Disconnect => "disconnect",
             Reducer::Tick { .. } => "tick",
             Reducer::UpdatePlayerInput { .. } => "update_player_input",
@@ -84,6 +107,10 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 &value.args,
             )?
             .into()),
+            "create_platform" => Ok(__sdk::parse_reducer_args::<
+                create_platform_reducer::CreatePlatformArgs,
+            >("create_platform", &value.args)?
+            .into()),
             "disconnect" => Ok(
                 __sdk::parse_reducer_args::<disconnect_reducer::DisconnectArgs>(
                     "disconnect",
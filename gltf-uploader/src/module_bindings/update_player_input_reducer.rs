@@ -11,6 +11,8 @@ use super::input_state_type::InputState;
 pub(super) struct UpdatePlayerInputArgs {
     pub input: InputState,
     pub rotation: f32,
+    pub pitch: f32,
+    pub roll: f32,
 }
 
 impl From<UpdatePlayerInputArgs> for super::Reducer {
@@ -18,6 +20,8 @@ impl From<UpdatePlayerInputArgs> for super::Reducer {
         Self::UpdatePlayerInput {
             input: args.input,
             rotation: args.rotation,
+            pitch: args.pitch,
+            roll: args.roll,
         }
     }
 }
@@ -38,7 +42,13 @@ pub trait update_player_input {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_update_player_input`] callbacks.
-    fn update_player_input(&self, input: InputState, rotation: f32) -> __sdk::Result<()>;
+    fn update_player_input(
+        &self,
+        input: InputState,
+        rotation: f32,
+        pitch: f32,
+        roll: f32,
+    ) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `update_player_input`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -48,7 +58,9 @@ pub trait update_player_input {
     /// to cancel the callback.
     fn on_update_player_input(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &InputState, &f32) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &InputState, &f32, &f32, &f32)
+            + Send
+            + 'static,
     ) -> UpdatePlayerInputCallbackId;
     /// Cancel a callback previously registered by [`Self::on_update_player_input`],
     /// causing it not to run in the future.
@@ -56,15 +68,28 @@ pub trait update_player_input {
 }
 
 impl update_player_input for super::RemoteReducers {
-    fn update_player_input(&self, input: InputState, rotation: f32) -> __sdk::Result<()> {
+    fn update_player_input(
+        &self,
+        input: InputState,
+        rotation: f32,
+        pitch: f32,
+        roll: f32,
+    ) -> __sdk::Result<()> {
         self.imp.call_reducer(
             "update_player_input",
-            UpdatePlayerInputArgs { input, rotation },
+            UpdatePlayerInputArgs {
+                input,
+                rotation,
+                pitch,
+                roll,
+            },
         )
     }
     fn on_update_player_input(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &InputState, &f32) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &InputState, &f32, &f32, &f32)
+            + Send
+            + 'static,
     ) -> UpdatePlayerInputCallbackId {
         UpdatePlayerInputCallbackId(self.imp.on_reducer(
             "update_player_input",
@@ -72,7 +97,13 @@ impl update_player_input for super::RemoteReducers {
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
-                            reducer: super::Reducer::UpdatePlayerInput { input, rotation },
+                            reducer:
+                                super::Reducer::UpdatePlayerInput {
+                                    input,
+                                    rotation,
+                                    pitch,
+                                    roll,
+                                },
                             ..
                         },
                     ..
@@ -80,7 +111,7 @@ impl update_player_input for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, input, rotation)
+                callback(ctx, input, rotation, pitch, roll)
             }),
         ))
     }
@@ -5,6 +5,8 @@
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 use super::db_vector_3_type::DbVector3;
+use super::surface_material_type::SurfaceMaterial;
+use super::volume_type_type::VolumeType;
 
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
@@ -12,6 +14,9 @@ pub(super) struct UploadBodyArgs {
     pub points: Vec<DbVector3>,
     pub indices: Vec<DbVector3>,
     pub name: String,
+    pub scene_version: u32,
+    pub material: SurfaceMaterial,
+    pub volume: Option<VolumeType>,
 }
 
 impl From<UploadBodyArgs> for super::Reducer {
@@ -20,6 +25,9 @@ impl From<UploadBodyArgs> for super::Reducer {
             points: args.points,
             indices: args.indices,
             name: args.name,
+            scene_version: args.scene_version,
+            material: args.material,
+            volume: args.volume,
         }
     }
 }
@@ -45,6 +53,9 @@ pub trait upload_body {
         points: Vec<DbVector3>,
         indices: Vec<DbVector3>,
         name: String,
+        scene_version: u32,
+        material: SurfaceMaterial,
+        volume: Option<VolumeType>,
     ) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `upload_body`.
     ///
@@ -55,8 +66,15 @@ pub trait upload_body {
     /// to cancel the callback.
     fn on_upload_body(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &Vec<DbVector3>, &Vec<DbVector3>, &String)
-            + Send
+        callback: impl FnMut(
+                &super::ReducerEventContext,
+                &Vec<DbVector3>,
+                &Vec<DbVector3>,
+                &String,
+                &u32,
+                &SurfaceMaterial,
+                &Option<VolumeType>,
+            ) + Send
             + 'static,
     ) -> UploadBodyCallbackId;
     /// Cancel a callback previously registered by [`Self::on_upload_body`],
@@ -70,6 +88,9 @@ impl upload_body for super::RemoteReducers {
         points: Vec<DbVector3>,
         indices: Vec<DbVector3>,
         name: String,
+        scene_version: u32,
+        material: SurfaceMaterial,
+        volume: Option<VolumeType>,
     ) -> __sdk::Result<()> {
         self.imp.call_reducer(
             "upload_body",
@@ -77,13 +98,23 @@ impl upload_body for super::RemoteReducers {
                 points,
                 indices,
                 name,
+                scene_version,
+                material,
+                volume,
             },
         )
     }
     fn on_upload_body(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &Vec<DbVector3>, &Vec<DbVector3>, &String)
-            + Send
+        mut callback: impl FnMut(
+                &super::ReducerEventContext,
+                &Vec<DbVector3>,
+                &Vec<DbVector3>,
+                &String,
+                &u32,
+                &SurfaceMaterial,
+                &Option<VolumeType>,
+            ) + Send
             + 'static,
     ) -> UploadBodyCallbackId {
         UploadBodyCallbackId(self.imp.on_reducer(
@@ -97,6 +128,9 @@ impl upload_body for super::RemoteReducers {
                                     points,
                                     indices,
                                     name,
+                                    scene_version,
+                                    material,
+                                    volume,
                                 },
                             ..
                         },
@@ -105,7 +139,7 @@ impl upload_body for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, points, indices, name)
+                callback(ctx, points, indices, name, scene_version, material, volume)
             }),
         ))
     }
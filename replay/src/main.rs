@@ -0,0 +1,118 @@
+mod module_bindings;
+mod simulation;
+
+use module_bindings::*;
+use spacetimedb_sdk::*;
+use std::sync::mpsc;
+
+const HOST: &str = "wss://maincloud.spacetimedb.com";
+// const HOST: &str = "ws://localhost:3000";
+const MODULE_NAME: &str = "vrchatdemo-gerbuuun";
+// `recorded_input` is private, so the replay has to connect with the token of the database owner
+const TOKEN_VAR: &str = "SPACETIME_TOKEN";
+// Replayed positions further than this from the recorded ones are reported
+const DIVERGENCE_THRESHOLD: f32 = 0.01;
+
+fn connect_to_db() -> DbConnection {
+    DbConnection::builder()
+        .with_uri(HOST)
+        .with_module_name(MODULE_NAME)
+        .with_token(std::env::var(TOKEN_VAR).ok())
+        .on_connect(on_connect)
+        .on_connect_error(on_connect_error)
+        .build()
+        .expect("Failed to connect to database")
+}
+
+fn on_connect(_ctx: &DbConnection, _identity: Identity, _token: &str) {
+    eprintln!("Connected to database");
+}
+
+fn on_connect_error(_ctx: &ErrorContext, error: Error) {
+    eprintln!("Failed to connect to database: {:?}", error);
+    std::process::exit(1);
+}
+
+// Replays the recorded inputs of an instance and prints the trajectories as CSV
+// Usage: SPACETIME_TOKEN=<owner token> replay [instance_id]
+// The instance defaults to the instance of the oldest recorded input
+//
+// The replay only runs player movement against the static colliders of the scene, it does not reproduce:
+// - moving platforms, which stay where they were uploaded
+// - seats, seated players keep being simulated instead of pinned to their seat
+// - portals, players stay in the replayed instance
+// - collisions between players and personal space, players never touch each other
+// - movement modes set by admins (fly, noclip), every player walks
+// Divergences reported while any of these were in use are expected
+pub fn main() {
+    let instance_id: Option<u32> = std::env::args()
+        .nth(1)
+        .map(|arg| arg.parse().expect("Instance id must be a number"));
+
+    let ctx = connect_to_db();
+    ctx.run_threaded();
+
+    // Take a copy of the colliders and the recording once the subscription is applied
+    let (sender, receiver) = mpsc::channel();
+    ctx.subscription_builder()
+        .on_applied(move |ctx| {
+            let colliders: Vec<Collider> = ctx.db.collider().iter().collect();
+            let inputs: Vec<RecordedInput> = ctx.db.recorded_input().iter().collect();
+            sender
+                .send((colliders, inputs))
+                .expect("Failed to send subscription data");
+        })
+        .on_error(|_ctx, error| {
+            eprintln!("Failed to subscribe: {:?}", error);
+            std::process::exit(1);
+        })
+        .subscribe(["SELECT * FROM collider", "SELECT * FROM recorded_input"]);
+
+    let (colliders, mut inputs) = receiver
+        .recv()
+        .expect("Failed to receive subscription data");
+    ctx.disconnect().expect("Failed to disconnect");
+
+    let Some(instance_id) = instance_id.or_else(|| {
+        inputs
+            .iter()
+            .min_by_key(|input| input.sequence)
+            .map(|input| input.instance_id)
+    }) else {
+        eprintln!("No inputs have been recorded");
+        return;
+    };
+    inputs.retain(|input| input.instance_id == instance_id);
+    let Some(scene_version) = inputs.first().map(|input| input.scene_version) else {
+        eprintln!("No inputs have been recorded in instance {}", instance_id);
+        return;
+    };
+
+    eprintln!(
+        "Replaying {} inputs of instance {} (scene version {})",
+        inputs.len(),
+        instance_id,
+        scene_version
+    );
+
+    let mut physics = simulation::load_physics(&colliders, scene_version);
+    let (frames, divergences) = simulation::replay(&mut physics, &inputs);
+
+    println!("tick,identity,x,y,z");
+    for frame in &frames {
+        println!(
+            "{},{},{},{},{}",
+            frame.tick, frame.identity, frame.position.x, frame.position.y, frame.position.z
+        );
+    }
+
+    for divergence in divergences
+        .iter()
+        .filter(|divergence| divergence.distance > DIVERGENCE_THRESHOLD)
+    {
+        eprintln!(
+            "{} diverged {:.3} from the recording at tick {}",
+            divergence.identity, divergence.distance, divergence.tick
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::module_bindings::{Collider, DbVector3, InputState, RecordedInput};
+use nalgebra::{Isometry3, UnitQuaternion, Vector3};
+use shared::math;
+use spacetime_module::physics::Physics;
+use spacetime_module::player::utils::normalize_yaw;
+use spacetime_module::{player, world};
+use spacetimedb_sdk::Identity;
+use std::collections::HashMap;
+
+// Position of a player after the server would have simulated a tick
+pub struct Frame {
+    pub tick: u64,
+    pub identity: Identity,
+    pub position: Vector3<f32>,
+}
+
+// How far the replayed trajectory of a player drifted from the recorded positions
+pub struct Divergence {
+    pub identity: Identity,
+    pub tick: u64,
+    pub distance: f32,
+}
+
+struct ReplayPlayer {
+    input: player::InputState,
+    rotation: UnitQuaternion<f32>,
+}
+
+// The generated vector has the same layout as `shared::math::DbVector3`
+impl From<DbVector3> for math::DbVector3 {
+    fn from(vector: DbVector3) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<InputState> for player::InputState {
+    fn from(input: InputState) -> Self {
+        Self {
+            forward: input.forward,
+            backward: input.backward,
+            left: input.left,
+            right: input.right,
+            jump: input.jump,
+            crouch: input.crouch,
+            is_pointer_locked: input.is_pointer_locked,
+        }
+    }
+}
+
+// Build the physics world of a scene version the same way the server loads an instance
+// Moving platforms are not animated, they stay where they were uploaded
+pub fn load_physics(colliders: &[Collider], scene_version: u32) -> Physics {
+    let mut physics = Physics::new();
+
+    for collider in colliders
+        .iter()
+        .filter(|collider| collider.scene_version == scene_version)
+    {
+        world::Collider {
+            id: collider.id,
+            positions: collider.positions.iter().cloned().map(Into::into).collect(),
+            indices: collider.indices.iter().cloned().map(Into::into).collect(),
+            name: collider.name.clone(),
+            scene_version: collider.scene_version,
+            material: collider.material,
+            volume: collider.volume,
+        }
+        .add_to_physics(&mut physics);
+    }
+
+    physics
+}
+
+// Run the recorded inputs of one instance through `physics`, tick by tick
+// Players are spawned at the position of their first recorded input and never collide with each other
+// This mirrors the player movement of the server tick only, see `main` for what isn't reproduced
+pub fn replay(physics: &mut Physics, inputs: &[RecordedInput]) -> (Vec<Frame>, Vec<Divergence>) {
+    let mut inputs: Vec<&RecordedInput> = inputs.iter().collect();
+    inputs.sort_by_key(|input| input.sequence);
+
+    let (Some(first), Some(last)) = (
+        inputs.iter().map(|input| input.tick).min(),
+        inputs.iter().map(|input| input.tick).max(),
+    ) else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let mut players: HashMap<Identity, ReplayPlayer> = HashMap::new();
+    let mut positions: HashMap<(Identity, u64), Vector3<f32>> = HashMap::new();
+    let mut frames = Vec::new();
+    let mut divergences = Vec::new();
+    let mut next_input = 0;
+
+    for tick in first..=last {
+        // Pick up every input that arrived before this tick, the last one of each player wins
+        while let Some(input) = inputs.get(next_input).filter(|input| input.tick == tick) {
+            next_input += 1;
+
+            let recorded: Vector3<f32> = math::DbVector3::from(input.position.clone()).into();
+            if let Some(replayed) = positions.get(&(input.identity, tick - 1)) {
+                divergences.push(Divergence {
+                    identity: input.identity,
+                    tick,
+                    distance: (replayed - recorded).magnitude(),
+                });
+            }
+
+            let rotation =
+                UnitQuaternion::from_axis_angle(&Vector3::y_axis(), normalize_yaw(input.rotation));
+            if !players.contains_key(&input.identity) {
+                physics.add_player(
+                    input.identity,
+                    Isometry3::from_parts(recorded.into(), rotation),
+                    false,
+                );
+            }
+            players.insert(
+                input.identity,
+                ReplayPlayer {
+                    input: input.input.clone().into(),
+                    rotation,
+                },
+            );
+        }
+
+        // Same order as the server tick: move the players, store their position, then step
+        for (identity, player) in &players {
+            if let Some(update) = physics.update_player(identity, &player.input, player.rotation) {
+                let position = update.rigid_body.position().translation.vector;
+                positions.insert((*identity, tick), position);
+                frames.push(Frame {
+                    tick,
+                    identity: *identity,
+                    position,
+                });
+            }
+        }
+        physics.tick();
+    }
+
+    (frames, divergences)
+}
@@ -0,0 +1,56 @@
+use crate::moderation::{audit, AuditAction};
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+// Identities allowed to change world settings and manage other players
+// The identity that publishes the module is registered as the first admin in `init`
+#[spacetimedb::table(name = admin, public)]
+#[derive(Clone, Debug)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub granted_by: Identity,
+    pub granted_at: Timestamp,
+}
+
+pub fn is_admin(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db.admin().identity().find(identity).is_some()
+}
+
+// Return an error if the caller is not an admin
+pub fn ensure_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if is_admin(ctx, ctx.sender) {
+        Ok(())
+    } else {
+        Err(format!("{} is not an admin", ctx.sender))
+    }
+}
+
+pub fn insert_admin(ctx: &ReducerContext, identity: Identity) {
+    if !is_admin(ctx, identity) {
+        ctx.db.admin().insert(Admin {
+            identity,
+            granted_by: ctx.sender,
+            granted_at: ctx.timestamp,
+        });
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn grant_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    insert_admin(ctx, identity);
+    audit(ctx, AuditAction::GrantAdmin, identity, String::new());
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn revoke_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if identity == ctx.sender {
+        return Err("Admins cannot revoke their own rights".to_string());
+    }
+    ctx.db.admin().identity().delete(identity);
+    audit(ctx, AuditAction::RevokeAdmin, identity, String::new());
+    Ok(())
+}
@@ -1,11 +1,22 @@
-mod math;
+pub mod admin;
+pub mod moderation;
 pub mod physics;
 pub mod player;
+pub mod query;
+pub mod replay;
+pub mod social;
+pub mod stats;
 pub mod world;
 
-use physics::PHYSICS;
-use player::{player as db_player, Player};
+use moderation::{mute_expiry_schedule, MuteExpirySchedule, MUTE_EXPIRY_INTERVAL_MICROS};
+use nalgebra::Vector3;
+use physics::{MovementState, PlayerUpdate, PHYSICS};
+use player::cleanup::{cleanup_schedule, CleanupSchedule, CLEANUP_INTERVAL_MICROS};
+use player::idle::{idle_check_schedule, IdleCheckSchedule, IDLE_CHECK_INTERVAL_MICROS};
+use player::player as db_player;
 use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration};
+use std::collections::HashSet;
+use world::instance::instance_physics;
 
 const TICK_INTERVAL_MICROS: i64 = 1_000_000 / 30;
 
@@ -20,11 +31,34 @@ pub struct TickSchedule {
 
 #[spacetimedb::reducer(init)]
 fn init(ctx: &ReducerContext) {
+    // The identity publishing the module is the first admin
+    admin::insert_admin(ctx, ctx.sender);
+
+    player::emote::insert_default_emotes(ctx);
+
     // Start the tick schedule
     ctx.db.tick_schedule().insert(TickSchedule {
         schedule_id: 0,
         scheduled_at: TimeDuration::from_micros(TICK_INTERVAL_MICROS).into(),
     });
+
+    // Start checking for idle players
+    ctx.db.idle_check_schedule().insert(IdleCheckSchedule {
+        schedule_id: 0,
+        scheduled_at: TimeDuration::from_micros(IDLE_CHECK_INTERVAL_MICROS).into(),
+    });
+
+    // Start purging old guests from the logged out players
+    ctx.db.cleanup_schedule().insert(CleanupSchedule {
+        schedule_id: 0,
+        scheduled_at: TimeDuration::from_micros(CLEANUP_INTERVAL_MICROS).into(),
+    });
+
+    // Start removing mutes that ran out
+    ctx.db.mute_expiry_schedule().insert(MuteExpirySchedule {
+        schedule_id: 0,
+        scheduled_at: TimeDuration::from_micros(MUTE_EXPIRY_INTERVAL_MICROS).into(),
+    });
 }
 
 #[spacetimedb::reducer]
@@ -34,26 +68,82 @@ fn tick(ctx: &ReducerContext, _schedule: TickSchedule) -> Result<(), String> {
         return Ok(());
     }
 
-    let mut physics = PHYSICS.lock().expect("Failed to lock physics");
+    replay::advance_tick(ctx);
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+
+    // Move the platforms before the players, so players can follow them
+    world::platform::update_platforms(ctx, &mut worlds);
+
+    // Only instances with players in them are simulated
+    let mut active_instances = HashSet::new();
 
     for mut player in ctx.db.player().iter() {
-        if let Some(rigid_body) = physics.update_player(&mut player) {
+        active_instances.insert(player.instance_id);
+        let physics = instance_physics(ctx, &mut worlds, player.instance_id);
+
+        // Seated players are pinned to their seat
+        if player.seat_id.is_some() {
+            continue;
+        }
+
+        let ground_surface = physics.player_ground_surface(&player.identity);
+        let movement_state = physics.player_movement_state(&player.identity, &player.input);
+        let previous_position: Vector3<f32> = player.position.into();
+
+        if let Some(PlayerUpdate { rigid_body, jumped }) = physics.update_player(
+            &player.identity,
+            &player.input,
+            player.body_position().rotation,
+        ) {
             let is_moving = rigid_body.linvel().xz().magnitude() > 0.00001;
             // TODO: Check if this is correct
             let backwards = player.input.backward && !player.input.forward;
 
-            player.animation_state = Some(match (is_moving, backwards) {
-                (true, true) => "walkingBackwards".to_string(),
-                (true, false) => "walkingForwards".to_string(),
-                (false, _) => "idle".to_string(),
-            });
+            // Swimming and climbing override everything else
+            // Emotes keep playing until they expire or the player moves
+            match movement_state {
+                Some(MovementState::Swimming) => {
+                    player.animation_state = Some("swimming".to_string());
+                }
+                Some(MovementState::Climbing(_)) => {
+                    player.animation_state = Some("climbing".to_string());
+                }
+                _ if !player::emote::update_emote(ctx, &mut player) => {
+                    player.animation_state = Some(match (is_moving, backwards) {
+                        (true, true) => "walkingBackwards".to_string(),
+                        (true, false) => "walkingForwards".to_string(),
+                        (false, _) => "idle".to_string(),
+                    });
+                }
+                _ => {}
+            }
             player.position = rigid_body.position().translation.vector.into();
+            player.ground_surface = ground_surface;
+
+            let delta = rigid_body.position().translation.vector - previous_position;
+            moderation::check_movement(
+                ctx,
+                player.identity,
+                delta,
+                TICK_INTERVAL_MICROS as f32 / 1_000_000.0,
+            );
+            stats::record_movement(ctx, player.identity, delta.magnitude(), jumped);
+
             ctx.db.player().identity().update(player);
         }
     }
 
-    // Calculate the next physics state
-    physics.tick();
+    // Unload the physics worlds of instances nobody is in anymore, they are rebuilt when someone joins
+    worlds.retain(|instance_id, _| active_instances.contains(instance_id));
+
+    // Calculate the next physics state of every active instance
+    for physics in worlds.values_mut() {
+        physics.tick();
+    }
+
+    // Move players that walked into a portal to their destination
+    world::portal::update_portals(ctx, &mut worlds);
 
     Ok(())
 }
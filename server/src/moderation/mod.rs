@@ -0,0 +1,257 @@
+pub mod reducers;
+
+use nalgebra::Vector3;
+use spacetimedb::{
+    client_visibility_filter, Filter, Identity, ReducerContext, ScheduleAt, SpacetimeType, Table,
+    Timestamp,
+};
+
+// Maximum number of `update_player_input` calls per identity per window
+pub const MAX_INPUTS_PER_WINDOW: u32 = 60;
+const INPUT_WINDOW_MICROS: i64 = 1_000_000;
+// Horizontal speed no player should reach, even when riding a platform
+const MAX_HORIZONTAL_SPEED: f32 = 15.0;
+// Distance a player can't possibly cover in a single tick
+const TELEPORT_DISTANCE: f32 = 5.0;
+// Minimum time between two flags of the same kind for the same identity
+const FLAG_COOLDOWN_MICROS: i64 = 10 * 1_000_000;
+pub const MUTE_EXPIRY_INTERVAL_MICROS: i64 = 60 * 1_000_000;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum FlagKind {
+    InvalidInput,
+    RateLimited,
+    Speeding,
+    Teleport,
+}
+
+// Suspicious activity of a player, to be reviewed by admins
+// Private like bans and the audit log, which only the module owner can query
+#[spacetimedb::table(name = moderation_flag)]
+#[derive(Clone, Debug)]
+pub struct ModerationFlag {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub identity: Identity,
+    pub kind: FlagKind,
+    pub details: String,
+    pub timestamp: Timestamp,
+}
+
+// Number of inputs received from an identity in the current window
+#[spacetimedb::table(name = input_rate_limit)]
+pub struct InputRateLimit {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub window_start: Timestamp,
+    pub count: u32,
+}
+
+// Identities that are not allowed to connect
+#[spacetimedb::table(name = ban)]
+#[derive(Clone, Debug)]
+pub struct Ban {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub reason: String,
+    pub banned_by: Identity,
+    pub banned_at: Timestamp,
+    // Permanent when not set
+    pub expires_at: Option<Timestamp>,
+}
+
+// Players that are not allowed to use chat and/or voice until the mute expires
+#[spacetimedb::table(name = mute, public)]
+#[derive(Clone, Debug)]
+pub struct Mute {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub chat: bool,
+    pub voice: bool,
+    pub reason: String,
+    pub muted_by: Identity,
+    pub expires_at: Timestamp,
+}
+
+// Muted players can see their own mute, nobody else can
+#[client_visibility_filter]
+const MUTE_VISIBILITY: Filter = Filter::Sql("SELECT * FROM mute WHERE identity = :sender");
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum MuteKind {
+    Chat,
+    Voice,
+}
+
+#[spacetimedb::table(name = mute_expiry_schedule, scheduled(remove_expired_mutes))]
+pub struct MuteExpirySchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub schedule_id: u64,
+
+    pub scheduled_at: ScheduleAt,
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum AuditAction {
+    GrantAdmin,
+    RevokeAdmin,
+    Ban,
+    Unban,
+    Kick,
+    Mute,
+    Unmute,
+}
+
+// Every action taken by an admin
+#[spacetimedb::table(name = audit_log)]
+#[derive(Clone, Debug)]
+pub struct AuditLog {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub admin: Identity,
+    pub action: AuditAction,
+    pub target: Identity,
+    pub details: String,
+    pub timestamp: Timestamp,
+}
+
+pub fn audit(ctx: &ReducerContext, action: AuditAction, target: Identity, details: String) {
+    log::info!("{} {:?} {}: {}", ctx.sender, action, target, details);
+    ctx.db.audit_log().insert(AuditLog {
+        id: 0,
+        admin: ctx.sender,
+        action,
+        target,
+        details,
+        timestamp: ctx.timestamp,
+    });
+}
+
+// Return an error if the caller has an active ban
+// Rejects their connection, and anything they try to do if they were banned while connected
+pub fn ensure_not_banned(ctx: &ReducerContext) -> Result<(), String> {
+    match ctx.db.ban().identity().find(ctx.sender) {
+        Some(ban)
+            if ban
+                .expires_at
+                .is_none_or(|expires_at| expires_at > ctx.timestamp) =>
+        {
+            Err(format!("You are banned: {}", ban.reason))
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn is_muted(ctx: &ReducerContext, identity: Identity, kind: MuteKind) -> bool {
+    ctx.db.mute().identity().find(identity).is_some_and(|mute| {
+        mute.expires_at > ctx.timestamp
+            && match kind {
+                MuteKind::Chat => mute.chat,
+                MuteKind::Voice => mute.voice,
+            }
+    })
+}
+
+// Delete mutes that ran out, so muted players see them go away
+#[spacetimedb::reducer]
+pub fn remove_expired_mutes(ctx: &ReducerContext, _schedule: MuteExpirySchedule) {
+    if ctx.sender != ctx.identity() {
+        log::error!("Player {} is not authorized to expire mutes", ctx.sender);
+        return;
+    }
+
+    let expired: Vec<_> = ctx
+        .db
+        .mute()
+        .iter()
+        .filter(|mute| mute.expires_at <= ctx.timestamp)
+        .map(|mute| mute.identity)
+        .collect();
+    for identity in expired {
+        ctx.db.mute().identity().delete(identity);
+    }
+}
+
+fn micros_since(ctx: &ReducerContext, earlier: Timestamp) -> i64 {
+    ctx.timestamp
+        .time_duration_since(earlier)
+        .map_or(0, |duration| duration.to_micros())
+}
+
+// Record suspicious activity, ignoring repeated flags of the same kind within the cooldown
+pub fn flag(ctx: &ReducerContext, identity: Identity, kind: FlagKind, details: String) {
+    let recently_flagged = ctx
+        .db
+        .moderation_flag()
+        .identity()
+        .filter(identity)
+        .any(|flag| flag.kind == kind && micros_since(ctx, flag.timestamp) < FLAG_COOLDOWN_MICROS);
+    if recently_flagged {
+        return;
+    }
+
+    log::warn!("Flagged {} for {:?}: {}", identity, kind, details);
+    ctx.db.moderation_flag().insert(ModerationFlag {
+        id: 0,
+        identity,
+        kind,
+        details,
+        timestamp: ctx.timestamp,
+    });
+}
+
+// Count an input from the caller, returning false if they exceeded the rate limit
+pub fn check_input_rate(ctx: &ReducerContext) -> bool {
+    let Some(mut limit) = ctx.db.input_rate_limit().identity().find(ctx.sender) else {
+        ctx.db.input_rate_limit().insert(InputRateLimit {
+            identity: ctx.sender,
+            window_start: ctx.timestamp,
+            count: 1,
+        });
+        return true;
+    };
+
+    if micros_since(ctx, limit.window_start) >= INPUT_WINDOW_MICROS {
+        limit.window_start = ctx.timestamp;
+        limit.count = 0;
+    }
+    limit.count += 1;
+    let allowed = limit.count <= MAX_INPUTS_PER_WINDOW;
+    ctx.db.input_rate_limit().identity().update(limit);
+
+    allowed
+}
+
+// Sanity check the movement of a player during a single tick
+pub fn check_movement(ctx: &ReducerContext, identity: Identity, delta: Vector3<f32>, seconds: f32) {
+    let distance = delta.magnitude();
+    if distance > TELEPORT_DISTANCE {
+        flag(
+            ctx,
+            identity,
+            FlagKind::Teleport,
+            format!("Moved {:.2}m in a single tick", distance),
+        );
+        return;
+    }
+
+    let horizontal_speed = delta.xz().magnitude() / seconds;
+    if horizontal_speed > MAX_HORIZONTAL_SPEED {
+        flag(
+            ctx,
+            identity,
+            FlagKind::Speeding,
+            format!("Moving at {:.2}m/s", horizontal_speed),
+        );
+    }
+}
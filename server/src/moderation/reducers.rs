@@ -0,0 +1,143 @@
+use crate::admin::{ensure_admin, is_admin};
+use crate::moderation::{audit, ban, mute, AuditAction, Ban, Mute};
+use crate::physics::PHYSICS;
+use crate::player::{log_out_player, player};
+use crate::world::instance::instance_physics;
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+fn ensure_can_moderate(ctx: &ReducerContext, target: Identity) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if is_admin(ctx, target) {
+        return Err("Admins cannot be moderated".to_string());
+    }
+    Ok(())
+}
+
+// Take the player out of the world if they are currently in it
+fn remove_from_world(ctx: &ReducerContext, identity: Identity) -> bool {
+    if let Some(player) = ctx.db.player().identity().find(identity) {
+        let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+        let physics = instance_physics(ctx, &mut worlds, player.instance_id);
+        log_out_player(ctx, physics, player);
+        true
+    } else {
+        false
+    }
+}
+
+// When a ban or mute of `secs` seconds issued now runs out
+fn expires_after(ctx: &ReducerContext, secs: u64) -> Result<Timestamp, String> {
+    i64::try_from(secs)
+        .ok()
+        .and_then(|secs| secs.checked_mul(1_000_000))
+        .and_then(|micros| {
+            ctx.timestamp
+                .to_micros_since_unix_epoch()
+                .checked_add(micros)
+        })
+        .map(Timestamp::from_micros_since_unix_epoch)
+        .ok_or_else(|| "Duration is too long".to_string())
+}
+
+#[spacetimedb::reducer]
+pub fn kick(ctx: &ReducerContext, identity: Identity, reason: String) -> Result<(), String> {
+    ensure_can_moderate(ctx, identity)?;
+    if !remove_from_world(ctx, identity) {
+        return Err("Player not found".to_string());
+    }
+
+    audit(ctx, AuditAction::Kick, identity, reason);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn ban_player(
+    ctx: &ReducerContext,
+    identity: Identity,
+    reason: String,
+    duration_secs: Option<u64>,
+) -> Result<(), String> {
+    ensure_can_moderate(ctx, identity)?;
+
+    let expires_at = duration_secs
+        .map(|secs| expires_after(ctx, secs))
+        .transpose()?;
+    let ban = Ban {
+        identity,
+        reason: reason.clone(),
+        banned_by: ctx.sender,
+        banned_at: ctx.timestamp,
+        expires_at,
+    };
+    if ctx.db.ban().identity().find(identity).is_some() {
+        ctx.db.ban().identity().update(ban);
+    } else {
+        ctx.db.ban().insert(ban);
+    }
+
+    remove_from_world(ctx, identity);
+    audit(ctx, AuditAction::Ban, identity, reason);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unban_player(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if !ctx.db.ban().identity().delete(identity) {
+        return Err("Player is not banned".to_string());
+    }
+
+    audit(ctx, AuditAction::Unban, identity, String::new());
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn mute_player(
+    ctx: &ReducerContext,
+    identity: Identity,
+    chat: bool,
+    voice: bool,
+    reason: String,
+    duration_secs: u64,
+) -> Result<(), String> {
+    ensure_can_moderate(ctx, identity)?;
+    if !chat && !voice {
+        return Err("A mute needs to cover chat, voice or both".to_string());
+    }
+
+    let mute = Mute {
+        identity,
+        chat,
+        voice,
+        reason: reason.clone(),
+        muted_by: ctx.sender,
+        expires_at: expires_after(ctx, duration_secs)?,
+    };
+    if ctx.db.mute().identity().find(identity).is_some() {
+        ctx.db.mute().identity().update(mute);
+    } else {
+        ctx.db.mute().insert(mute);
+    }
+
+    audit(
+        ctx,
+        AuditAction::Mute,
+        identity,
+        format!(
+            "chat: {}, voice: {}, {}s: {}",
+            chat, voice, duration_secs, reason
+        ),
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unmute_player(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if !ctx.db.mute().identity().delete(identity) {
+        return Err("Player is not muted".to_string());
+    }
+
+    audit(ctx, AuditAction::Unmute, identity, String::new());
+    Ok(())
+}
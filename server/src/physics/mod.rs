@@ -1,15 +1,68 @@
+pub mod query;
+pub mod snapshot;
 pub mod utils;
 
-use crate::Player;
-use nalgebra::Vector3;
+#[cfg(test)]
+mod tests;
+
+use crate::player::{InputState, MovementMode};
+use nalgebra::{Unit, UnitQuaternion, Vector3};
 use rapier3d::prelude::*;
+use shared::material::{SurfaceMaterial, SurfaceType};
+use shared::volume::VolumeType;
+use spacetimedb::Identity;
 use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
 use std::sync::LazyLock;
 use std::sync::Mutex;
 
 const MOVEMENT_SPEED: f32 = 3.0;
+const JUMP_SPEED: f32 = 5.0;
+
+const SWIM_SPEED: f32 = 2.0;
+// Vertical speed while swimming up (jump) or down (crouch)
+const SWIM_VERTICAL_SPEED: f32 = 2.0;
+// Fraction of the gravity that still applies in water
+const SWIM_GRAVITY_SCALE: f32 = 0.1;
+// Vertical velocity kept every step in water, and the upwards push of the water
+// Together with the reduced gravity, idle swimmers slowly float up to the surface
+const WATER_DRAG: f32 = 0.9;
+const BUOYANCY: f32 = 0.05;
+// Height above the feet that has to be under water to swim instead of walk
+const SWIM_DEPTH: f32 = 1.2;
+
+const CLIMB_SPEED: f32 = 2.0;
+// Part of the walking velocity kept while climbing, so players can step off at the top
+const CLIMB_WALK_FACTOR: f32 = 0.5;
+// Height above the feet that has to be inside a climbable volume to climb
+const CLIMB_HEIGHT: f32 = 0.9;
+
+const FLY_SPEED: f32 = 8.0;
+const FLY_VERTICAL_SPEED: f32 = 5.0;
+
+// How a player moves, picked every tick from the volumes they are in and their input
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MovementState {
+    Walking,
+    // Flying or noclipping, set by the player's movement mode
+    Flying,
+    Swimming,
+    // Moving along the up vector of a climbable volume
+    Climbing(Unit<Vector3<f32>>),
+}
+
+// Result of moving a player for one tick
+pub struct PlayerUpdate<'a> {
+    pub rigid_body: &'a RigidBody,
+    // Whether the jump impulse was applied this tick
+    pub jumped: bool,
+}
 
-pub static PHYSICS: LazyLock<Mutex<Physics>> = LazyLock::new(|| Mutex::new(Physics::new()));
+// Every instance of the world has its own physics world, keyed by instance id
+pub type PhysicsWorlds = HashMap<u32, Physics>;
+
+pub static PHYSICS: LazyLock<Mutex<PhysicsWorlds>> = LazyLock::new(|| Mutex::new(HashMap::new()));
 
 const SCENE_GROUP: Group = Group::GROUP_1;
 const PLAYER_GROUP: Group = Group::GROUP_2;
@@ -19,9 +72,31 @@ pub static SCENE_COLLISION_GROUP: LazyLock<InteractionGroups> =
 pub static PLAYER_COLLISION_GROUP: LazyLock<InteractionGroups> =
     LazyLock::new(|| InteractionGroups::new(PLAYER_GROUP, Group::ALL ^ PLAYER_GROUP));
 
-pub struct Physics {
+// Collision groups of a player's capsule
+// Players only collide with each other when both of them have player collisions enabled
+pub fn player_collision_groups(collide_with_players: bool) -> InteractionGroups {
+    if collide_with_players {
+        InteractionGroups::new(PLAYER_GROUP, Group::ALL)
+    } else {
+        *PLAYER_COLLISION_GROUP
+    }
+}
+
+// Key used to look up the rigid bodies of players
+// The module uses identities, tests can use anything else
+pub trait PlayerKey: Copy + Eq + Hash + Debug {}
+
+impl<K: Copy + Eq + Hash + Debug> PlayerKey for K {}
+
+pub struct Physics<K: PlayerKey = Identity> {
     pub physics_pipeline: PhysicsPipeline,
-    pub players: HashMap<spacetimedb::Identity, RigidBodyHandle>,
+    pub players: HashMap<K, RigidBodyHandle>,
+    pub scene_colliders: HashMap<ColliderHandle, u32>,
+    pub platforms: HashMap<u32, RigidBodyHandle>,
+    pub portals: HashMap<ColliderHandle, u32>,
+    pub volumes: HashMap<ColliderHandle, VolumeType>,
+    // Players that don't walk, everyone else is missing from here
+    pub movement_modes: HashMap<K, MovementMode>,
 
     pub gravity: Vector3<f32>,
     pub integration_parameters: IntegrationParameters,
@@ -38,11 +113,22 @@ pub struct Physics {
     pub event_handler: (),
 }
 
-impl Physics {
+impl<K: PlayerKey> Default for Physics<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PlayerKey> Physics<K> {
     pub fn new() -> Self {
         Self {
             physics_pipeline: PhysicsPipeline::new(),
             players: HashMap::new(),
+            scene_colliders: HashMap::new(),
+            platforms: HashMap::new(),
+            portals: HashMap::new(),
+            volumes: HashMap::new(),
+            movement_modes: HashMap::new(),
             gravity: Vector3::new(0.0, -20.0, 0.0),
             integration_parameters: IntegrationParameters::default(),
             island_manager: IslandManager::new(),
@@ -78,32 +164,216 @@ impl Physics {
         );
     }
 
-    // Add a collider to the physics world
-    pub fn add_collider(&mut self, collider: Collider) {
-        self.collider_set.insert(collider);
+    // Add a scene collider to the physics world, remembering which `collider` row it belongs to
+    pub fn add_collider(&mut self, id: u32, collider: Collider) {
+        let handle = self.collider_set.insert(collider);
+        self.scene_colliders.insert(handle, id);
+    }
+
+    // Remove every physics collider created from the given `collider` row
+    pub fn remove_collider(&mut self, id: u32) {
+        let handles: Vec<ColliderHandle> = self
+            .scene_colliders
+            .iter()
+            .filter(|(_, collider_id)| **collider_id == id)
+            .map(|(handle, _)| *handle)
+            .collect();
+
+        for handle in handles {
+            self.collider_set.remove(
+                handle,
+                &mut self.island_manager,
+                &mut self.rigid_body_set,
+                true,
+            );
+            self.scene_colliders.remove(&handle);
+            self.volumes.remove(&handle);
+        }
+    }
+
+    // Add a volume sensor to the physics world, remembering which `collider` row it belongs to
+    pub fn add_volume(&mut self, id: u32, volume: VolumeType, collider: Collider) {
+        let handle = self.collider_set.insert(collider);
+        self.scene_colliders.insert(handle, id);
+        self.volumes.insert(handle, volume);
+    }
+
+    // The first volume of the given type containing the point
+    fn volume_at(&self, point: &Point<f32>, volume: VolumeType) -> Option<&Collider> {
+        self.volumes
+            .iter()
+            .filter(|(_, volume_type)| **volume_type == volume)
+            .filter_map(|(handle, _)| self.collider_set.get(*handle))
+            .find(|collider| utils::contains_point(collider, point))
+    }
+
+    // Whether the given point is inside a volume of the given type
+    pub fn is_in_volume(&self, point: &Point<f32>, volume: VolumeType) -> bool {
+        self.volume_at(point, volume).is_some()
+    }
+
+    // Climbing takes precedence over swimming, so ladders can lead out of the water
+    fn movement_state(&self, key: &K, rigid_body: &RigidBody, input: &InputState) -> MovementState {
+        if self.movement_modes.contains_key(key) {
+            return MovementState::Flying;
+        }
+
+        if input.forward || input.crouch {
+            let body = rigid_body.position() * Point::new(0.0, CLIMB_HEIGHT, 0.0);
+            if let Some(volume) = self.volume_at(&body, VolumeType::Climbable) {
+                return MovementState::Climbing(utils::climb_direction(volume));
+            }
+        }
+
+        let chest = rigid_body.position() * Point::new(0.0, SWIM_DEPTH, 0.0);
+        if self.is_in_volume(&chest, VolumeType::Water) {
+            MovementState::Swimming
+        } else {
+            MovementState::Walking
+        }
+    }
+
+    // Change the material of every physics collider created from the given `collider` row
+    pub fn set_collider_material(&mut self, id: u32, material: &SurfaceMaterial) {
+        for (handle, collider_id) in &self.scene_colliders {
+            if *collider_id != id {
+                continue;
+            }
+            if let Some(collider) = self.collider_set.get_mut(*handle) {
+                collider.set_friction(material.friction);
+                collider.set_restitution(material.restitution);
+                collider.user_data = material.surface.to_user_data();
+            }
+        }
+    }
+
+    // Attach a scene collider to a kinematic body so it can be moved around as a platform
+    // The static version of the collider is removed from the world
+    pub fn add_platform(&mut self, platform_id: u32, collider_id: u32, collider: Collider) {
+        self.remove_collider(collider_id);
+
+        let rigid_body = RigidBodyBuilder::kinematic_position_based().build();
+        let rigid_body_handle = self.rigid_body_set.insert(rigid_body);
+        let collider_handle = self.collider_set.insert_with_parent(
+            collider,
+            rigid_body_handle,
+            &mut self.rigid_body_set,
+        );
+        self.scene_colliders.insert(collider_handle, collider_id);
+        self.platforms.insert(platform_id, rigid_body_handle);
+    }
+
+    // Remove a platform together with its colliders
+    pub fn remove_platform(&mut self, platform_id: u32) {
+        if let Some(handle) = self.platforms.remove(&platform_id) {
+            if let Some(rigid_body) = self.rigid_body_set.get(handle) {
+                for collider_handle in rigid_body.colliders() {
+                    self.scene_colliders.remove(collider_handle);
+                }
+            }
+            self.rigid_body_set.remove(
+                handle,
+                &mut self.island_manager,
+                &mut self.collider_set,
+                &mut self.impulse_joint_set,
+                &mut self.multibody_joint_set,
+                true,
+            );
+        }
+    }
+
+    // Set the position the platform should reach at the end of the next step
+    pub fn move_platform(&mut self, platform_id: u32, translation: Vector3<f32>) {
+        if let Some(rigid_body) = self
+            .platforms
+            .get(&platform_id)
+            .and_then(|handle| self.rigid_body_set.get_mut(*handle))
+        {
+            rigid_body.set_next_kinematic_translation(translation);
+        }
+    }
+
+    // Velocity of the kinematic body the given collider is attached to
+    fn platform_velocity(&self, collider: ColliderHandle) -> Option<Vector3<f32>> {
+        let parent = self.collider_set.get(collider)?.parent()?;
+        let rigid_body = self.rigid_body_set.get(parent)?;
+        if rigid_body.is_kinematic() {
+            Some(*rigid_body.linvel())
+        } else {
+            None
+        }
+    }
+
+    // Add a portal sensor to the physics world
+    pub fn add_portal(&mut self, portal_id: u32, collider: Collider) {
+        let handle = self.collider_set.insert(collider);
+        self.portals.insert(handle, portal_id);
+    }
+
+    // Remove the sensor of the given portal
+    pub fn remove_portal(&mut self, portal_id: u32) {
+        self.portals.retain(|handle, id| {
+            if *id != portal_id {
+                return true;
+            }
+            self.collider_set.remove(
+                *handle,
+                &mut self.island_manager,
+                &mut self.rigid_body_set,
+                true,
+            );
+            false
+        });
+    }
+
+    // Players whose capsule overlapped a portal sensor during the last step, with the portal id
+    pub fn players_in_portals(&self) -> Vec<(K, u32)> {
+        let mut result = Vec::new();
+        for (handle, portal_id) in &self.portals {
+            for (collider1, collider2, intersecting) in
+                self.narrow_phase.intersection_pairs_with(*handle)
+            {
+                if !intersecting {
+                    continue;
+                }
+                let other = if collider1 == *handle {
+                    collider2
+                } else {
+                    collider1
+                };
+                if let Some(key) = self.player_for_collider(other) {
+                    result.push((key, *portal_id));
+                }
+            }
+        }
+        result
     }
 
     // Add the player to the physics world
-    pub fn add_player(&mut self, player: &Player) {
+    pub fn add_player(&mut self, key: K, position: Isometry<f32>, collide_with_players: bool) {
+        if self.players.contains_key(&key) {
+            return;
+        }
+
         let rigid_body = RigidBodyBuilder::dynamic()
-            .position(player.position())
+            .position(position)
             .lock_rotations()
             .ccd_enabled(true)
             .build();
         let collider = ColliderBuilder::capsule_y(0.6, 0.3)
             .translation(Vector3::new(0.0, 0.9, 0.0))
-            .collision_groups(*PLAYER_COLLISION_GROUP)
+            .collision_groups(player_collision_groups(collide_with_players))
             .build();
         let rigid_body_handle = self.rigid_body_set.insert(rigid_body);
         self.collider_set
             .insert_with_parent(collider, rigid_body_handle, &mut self.rigid_body_set);
-        self.players.insert(player.identity, rigid_body_handle);
-        log::info!("Added player to physics world: {:?}", player.identity);
+        self.players.insert(key, rigid_body_handle);
+        log::info!("Added player to physics world: {:?}", key);
     }
 
     // Remove the player from the physics world
-    pub fn remove_player(&mut self, player: &Player) {
-        if let Some(handle) = self.players.remove(&player.identity) {
+    pub fn remove_player(&mut self, key: &K) {
+        if let Some(handle) = self.players.remove(key) {
             self.rigid_body_set.remove(
                 handle,
                 &mut self.island_manager,
@@ -112,15 +382,134 @@ impl Physics {
                 &mut self.multibody_joint_set,
                 true,
             );
-            log::info!("Removed player from physics world: {:?}", player.identity);
+            self.movement_modes.remove(key);
+            log::info!("Removed player from physics world: {:?}", key);
         }
     }
 
-    // Move the player in the physics world
-    pub fn update_player(&mut self, player: &Player) -> Option<&RigidBody> {
-        if let Some(handle) = self.players.get_mut(&player.identity) {
+    // Move the player's rigid body without touching its velocity
+    pub fn set_player_position(&mut self, key: &K, position: Isometry<f32>) {
+        if let Some(rigid_body) = self
+            .players
+            .get(key)
+            .and_then(|handle| self.rigid_body_set.get_mut(*handle))
+        {
+            rigid_body.set_position(position, true);
+        }
+    }
+
+    // Disable the player's rigid body and pin it to the given transform (e.g. a seat)
+    pub fn pin_player(&mut self, key: &K, position: Isometry<f32>) {
+        if let Some(rigid_body) = self
+            .players
+            .get(key)
+            .and_then(|handle| self.rigid_body_set.get_mut(*handle))
+        {
+            rigid_body.set_position(position, true);
+            rigid_body.set_linvel(Vector3::zeros(), true);
+            rigid_body.set_enabled(false);
+        }
+    }
+
+    // Re-enable the player's rigid body at the given transform
+    pub fn release_player(&mut self, key: &K, position: Isometry<f32>) {
+        if let Some(rigid_body) = self
+            .players
+            .get(key)
+            .and_then(|handle| self.rigid_body_set.get_mut(*handle))
+        {
+            rigid_body.set_enabled(true);
+            rigid_body.set_position(position, true);
+        }
+    }
+
+    fn set_player_collision_groups(&mut self, key: &K, groups: InteractionGroups) {
+        if let Some(rigid_body) = self
+            .players
+            .get(key)
+            .and_then(|handle| self.rigid_body_set.get(*handle))
+        {
+            for collider_handle in rigid_body.colliders() {
+                if let Some(collider) = self.collider_set.get_mut(*collider_handle) {
+                    collider.set_collision_groups(groups);
+                }
+            }
+        }
+    }
+
+    // Enable or disable collisions between the player and other players
+    // Noclipping players keep colliding with nothing
+    pub fn set_player_collisions(&mut self, key: &K, enabled: bool) {
+        if self.movement_modes.get(key) != Some(&MovementMode::Noclip) {
+            self.set_player_collision_groups(key, player_collision_groups(enabled));
+        }
+    }
+
+    // Noclip takes the player's collider out of every collision group
+    // The rigid body stays in the world, so its position is still tracked
+    pub fn set_player_movement_mode(
+        &mut self,
+        key: &K,
+        mode: MovementMode,
+        collide_with_players: bool,
+    ) {
+        if !self.players.contains_key(key) {
+            return;
+        }
+
+        if mode == MovementMode::Walk {
+            self.movement_modes.remove(key);
+        } else {
+            self.movement_modes.insert(*key, mode);
+        }
+
+        let groups = if mode == MovementMode::Noclip {
+            InteractionGroups::none()
+        } else {
+            player_collision_groups(collide_with_players)
+        };
+        self.set_player_collision_groups(key, groups);
+    }
+
+    // Whether the player is currently standing on something
+    pub fn is_player_on_ground(&self, key: &K) -> bool {
+        self.players
+            .get(key)
+            .and_then(|handle| self.rigid_body_set.get(*handle))
+            .is_some_and(|rigid_body| utils::is_on_ground(&self.narrow_phase, rigid_body))
+    }
+
+    // How the player will move with the given input
+    pub fn player_movement_state(&self, key: &K, input: &InputState) -> Option<MovementState> {
+        let rigid_body = self.rigid_body_set.get(*self.players.get(key)?)?;
+        Some(self.movement_state(key, rigid_body, input))
+    }
+
+    // Surface of the scene collider the player is standing on
+    pub fn player_ground_surface(&self, key: &K) -> Option<SurfaceType> {
+        let rigid_body = self.rigid_body_set.get(*self.players.get(key)?)?;
+        let ground = utils::ground_collider(&self.narrow_phase, rigid_body)?;
+        if !self.scene_colliders.contains_key(&ground) {
+            return None;
+        }
+        Some(SurfaceType::from_user_data(
+            self.collider_set.get(ground)?.user_data,
+        ))
+    }
+
+    // Move the player in the physics world, relative to the direction their body is facing
+    pub fn update_player(
+        &mut self,
+        key: &K,
+        input: &InputState,
+        rotation: UnitQuaternion<f32>,
+    ) -> Option<PlayerUpdate<'_>> {
+        if let Some(handle) = self.players.get(key) {
+            let ground = utils::ground_collider(&self.narrow_phase, &self.rigid_body_set[*handle]);
+            // Players standing on a moving platform move along with it
+            let platform_velocity = ground.and_then(|collider| self.platform_velocity(collider));
+            let state = self.movement_state(key, &self.rigid_body_set[*handle], input);
             let rigid_body = self.rigid_body_set.get_mut(*handle).unwrap();
-            let input = player.input;
             let mut transform = Vector3::new(
                 if input.left { 1.0 } else { 0.0 } - if input.right { 1.0 } else { 0.0 },
                 0.0,
@@ -132,17 +521,64 @@ impl Physics {
                 transform = transform.normalize();
             }
 
-            transform *= MOVEMENT_SPEED;
-            transform = player.position().rotation.transform_vector(&transform);
-            transform.y = if input.jump && utils::is_on_ground(&self.narrow_phase, rigid_body) {
-                5.0
-            } else {
-                rigid_body.linvel().y
+            transform *= match state {
+                MovementState::Flying => FLY_SPEED,
+                MovementState::Swimming => SWIM_SPEED,
+                _ => MOVEMENT_SPEED,
             };
+            transform = rotation.transform_vector(&transform);
+
+            let mut jumped = false;
+            match state {
+                MovementState::Flying => {
+                    transform.y = if input.jump {
+                        FLY_VERTICAL_SPEED
+                    } else if input.crouch {
+                        -FLY_VERTICAL_SPEED
+                    } else {
+                        0.0
+                    };
+                    rigid_body.set_gravity_scale(0.0, true);
+                }
+                MovementState::Climbing(up) => {
+                    let climb = if input.forward { 1.0 } else { 0.0 }
+                        - if input.crouch { 1.0 } else { 0.0 };
+                    transform =
+                        transform * CLIMB_WALK_FACTOR + up.into_inner() * climb * CLIMB_SPEED;
+                    rigid_body.set_gravity_scale(0.0, true);
+                }
+                MovementState::Swimming => {
+                    transform.y = if input.jump {
+                        SWIM_VERTICAL_SPEED
+                    } else if input.crouch {
+                        -SWIM_VERTICAL_SPEED
+                    } else {
+                        rigid_body.linvel().y * WATER_DRAG + BUOYANCY
+                    };
+                    rigid_body.set_gravity_scale(SWIM_GRAVITY_SCALE, true);
+                }
+                MovementState::Walking => {
+                    jumped = input.jump && ground.is_some();
+                    transform.y = if jumped {
+                        JUMP_SPEED
+                    } else {
+                        rigid_body.linvel().y
+                    };
+                    rigid_body.set_gravity_scale(1.0, true);
+
+                    if let Some(velocity) = platform_velocity {
+                        transform.x += velocity.x;
+                        transform.z += velocity.z;
+                        if !input.jump {
+                            transform.y = velocity.y;
+                        }
+                    }
+                }
+            }
 
             rigid_body.set_linvel(transform, true);
 
-            Some(rigid_body)
+            Some(PlayerUpdate { rigid_body, jumped })
         } else {
             None
         }
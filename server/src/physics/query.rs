@@ -0,0 +1,124 @@
+use super::{Physics, PlayerKey};
+use nalgebra::Vector3;
+use rapier3d::parry::query::ShapeCastOptions;
+use rapier3d::prelude::*;
+
+// A single hit returned by one of the scene queries below, in world space
+#[derive(Debug, Clone, Copy)]
+pub struct QueryHit {
+    pub collider: ColliderHandle,
+    pub point: Point<f32>,
+    pub normal: Vector3<f32>,
+    pub distance: f32,
+}
+
+impl<K: PlayerKey> Physics<K> {
+    // Build a query filter that skips the rigid body of the given player (if any),
+    // so players don't hit their own capsule when casting from their position
+    // Sensors (volumes and portals) are only included when asked for, casts go through them
+    fn query_filter(&self, exclude: Option<&K>, include_sensors: bool) -> QueryFilter<'_> {
+        let filter = if include_sensors {
+            QueryFilter::default()
+        } else {
+            QueryFilter::default().exclude_sensors()
+        };
+        match exclude.and_then(|key| self.players.get(key)) {
+            Some(handle) => filter.exclude_rigid_body(*handle),
+            None => filter,
+        }
+    }
+
+    // Cast a ray and return the closest hit within `max_distance`
+    pub fn cast_ray(
+        &self,
+        origin: Point<f32>,
+        direction: Vector3<f32>,
+        max_distance: f32,
+        exclude: Option<&K>,
+    ) -> Option<QueryHit> {
+        let ray = Ray::new(origin, direction.normalize());
+        self.query_pipeline
+            .cast_ray_and_get_normal(
+                &self.rigid_body_set,
+                &self.collider_set,
+                &ray,
+                max_distance,
+                true,
+                self.query_filter(exclude, false),
+            )
+            .map(|(collider, intersection)| QueryHit {
+                collider,
+                point: ray.point_at(intersection.time_of_impact),
+                normal: intersection.normal,
+                distance: intersection.time_of_impact,
+            })
+    }
+
+    // Sweep a sphere along `direction` and return the first collider it touches
+    pub fn cast_sphere(
+        &self,
+        origin: Point<f32>,
+        direction: Vector3<f32>,
+        radius: f32,
+        max_distance: f32,
+        exclude: Option<&K>,
+    ) -> Option<QueryHit> {
+        let direction = direction.normalize();
+        let shape = Ball::new(radius);
+        let shape_pos = Isometry::translation(origin.x, origin.y, origin.z);
+        let options = ShapeCastOptions {
+            compute_impact_geometry_on_penetration: true,
+            ..ShapeCastOptions::with_max_time_of_impact(max_distance)
+        };
+
+        let (collider, hit) = self.query_pipeline.cast_shape(
+            &self.rigid_body_set,
+            &self.collider_set,
+            &shape_pos,
+            &direction,
+            &shape,
+            options,
+            self.query_filter(exclude, false),
+        )?;
+
+        // The witness and normal on the hit collider are expressed in its local space
+        let collider_pos = self.collider_set.get(collider)?.position();
+        Some(QueryHit {
+            collider,
+            point: collider_pos * hit.witness2,
+            normal: collider_pos * hit.normal2.into_inner(),
+            distance: hit.time_of_impact,
+        })
+    }
+
+    // Return every collider containing the given point, including volumes if `include_volumes` is set
+    // Scene trimeshes are oriented, so points enclosed by closed scene geometry are contained too
+    pub fn colliders_containing_point(
+        &self,
+        point: Point<f32>,
+        exclude: Option<&K>,
+        include_volumes: bool,
+    ) -> Vec<ColliderHandle> {
+        let mut colliders = Vec::new();
+        self.query_pipeline.intersections_with_point(
+            &self.rigid_body_set,
+            &self.collider_set,
+            &point,
+            self.query_filter(exclude, include_volumes),
+            |handle| {
+                colliders.push(handle);
+                true
+            },
+        );
+        colliders
+    }
+
+    // Find the player owning the given collider, if it belongs to a player's rigid body
+    pub fn player_for_collider(&self, handle: ColliderHandle) -> Option<K> {
+        let parent = self.collider_set.get(handle)?.parent()?;
+        self.players
+            .iter()
+            .find(|(_, body)| **body == parent)
+            .map(|(key, _)| *key)
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Physics, PlayerKey};
+use crate::player::MovementMode;
+use nalgebra::Vector3;
+use rapier3d::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use shared::volume::VolumeType;
+
+// Everything needed to continue a simulation where it left off
+// The query pipeline is not included, it is rebuilt from the colliders on restore
+#[derive(Serialize, Deserialize)]
+struct PhysicsState<K> {
+    players: Vec<(K, RigidBodyHandle)>,
+    scene_colliders: Vec<(ColliderHandle, u32)>,
+    platforms: Vec<(u32, RigidBodyHandle)>,
+    portals: Vec<(ColliderHandle, u32)>,
+    volumes: Vec<(ColliderHandle, VolumeType)>,
+    movement_modes: Vec<(K, MovementMode)>,
+
+    gravity: Vector3<f32>,
+    integration_parameters: IntegrationParameters,
+    island_manager: IslandManager,
+    broad_phase: DefaultBroadPhase,
+    narrow_phase: NarrowPhase,
+    rigid_body_set: RigidBodySet,
+    collider_set: ColliderSet,
+    impulse_joint_set: ImpulseJointSet,
+    multibody_joint_set: MultibodyJointSet,
+    ccd_solver: CCDSolver,
+}
+
+impl<K: PlayerKey + Serialize + DeserializeOwned> Physics<K> {
+    // Serialize the whole simulation state, including velocities and contacts
+    pub fn snapshot(&self) -> Result<Vec<u8>, String> {
+        let state = PhysicsState {
+            players: self.players.iter().map(|(k, h)| (*k, *h)).collect(),
+            scene_colliders: self
+                .scene_colliders
+                .iter()
+                .map(|(h, id)| (*h, *id))
+                .collect(),
+            platforms: self.platforms.iter().map(|(id, h)| (*id, *h)).collect(),
+            portals: self.portals.iter().map(|(h, id)| (*h, *id)).collect(),
+            volumes: self.volumes.iter().map(|(h, v)| (*h, *v)).collect(),
+            movement_modes: self.movement_modes.iter().map(|(k, m)| (*k, *m)).collect(),
+            gravity: self.gravity,
+            integration_parameters: self.integration_parameters,
+            island_manager: self.island_manager.clone(),
+            broad_phase: self.broad_phase.clone(),
+            narrow_phase: self.narrow_phase.clone(),
+            rigid_body_set: self.rigid_body_set.clone(),
+            collider_set: self.collider_set.clone(),
+            impulse_joint_set: self.impulse_joint_set.clone(),
+            multibody_joint_set: self.multibody_joint_set.clone(),
+            ccd_solver: self.ccd_solver.clone(),
+        };
+
+        // Bincode keeps the snapshot compact and round-trips the NaN impulses of resting contacts
+        bincode::serialize(&state).map_err(|e| format!("Failed to serialize physics: {}", e))
+    }
+
+    // Rebuild a physics world from the output of `snapshot`
+    pub fn restore(snapshot: &[u8]) -> Result<Self, String> {
+        let state: PhysicsState<K> = bincode::deserialize(snapshot)
+            .map_err(|e| format!("Failed to deserialize physics: {}", e))?;
+
+        let mut physics = Self {
+            players: state.players.into_iter().collect(),
+            scene_colliders: state.scene_colliders.into_iter().collect(),
+            platforms: state.platforms.into_iter().collect(),
+            portals: state.portals.into_iter().collect(),
+            volumes: state.volumes.into_iter().collect(),
+            movement_modes: state.movement_modes.into_iter().collect(),
+            gravity: state.gravity,
+            integration_parameters: state.integration_parameters,
+            island_manager: state.island_manager,
+            broad_phase: state.broad_phase,
+            narrow_phase: state.narrow_phase,
+            rigid_body_set: state.rigid_body_set,
+            collider_set: state.collider_set,
+            impulse_joint_set: state.impulse_joint_set,
+            multibody_joint_set: state.multibody_joint_set,
+            ccd_solver: state.ccd_solver,
+            ..Self::new()
+        };
+        physics.query_pipeline.update(&physics.collider_set);
+
+        Ok(physics)
+    }
+}
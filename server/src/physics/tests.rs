@@ -0,0 +1,418 @@
+use super::*;
+use crate::player::MovementMode;
+use crate::world::Collider as ColliderRow;
+use shared::math::DbVector3;
+use std::f32::consts::FRAC_PI_2;
+
+const PLAYER: u32 = 1;
+
+fn step(physics: &mut Physics<u32>, ticks: usize) {
+    for _ in 0..ticks {
+        physics.tick();
+    }
+}
+
+fn add_floor(physics: &mut Physics<u32>) {
+    physics.add_collider(
+        0,
+        ColliderBuilder::cuboid(50.0, 0.5, 50.0)
+            .translation(Vector3::new(0.0, -0.5, 0.0))
+            .collision_groups(*SCENE_COLLISION_GROUP)
+            .build(),
+    );
+}
+
+fn add_player_at(physics: &mut Physics<u32>, y: f32) {
+    physics.add_player(PLAYER, Isometry::translation(0.0, y, 0.0), false);
+}
+
+fn player_body(physics: &Physics<u32>) -> &RigidBody {
+    &physics.rigid_body_set[physics.players[&PLAYER]]
+}
+
+fn input(forward: bool, left: bool, jump: bool) -> InputState {
+    InputState {
+        forward,
+        left,
+        jump,
+        ..InputState::new()
+    }
+}
+
+fn yaw(angle: f32) -> UnitQuaternion<f32> {
+    UnitQuaternion::from_axis_angle(&Vector3::y_axis(), angle)
+}
+
+#[test]
+fn add_and_remove_player() {
+    let mut physics = Physics::new();
+    add_player_at(&mut physics, 0.0);
+    assert!(physics.players.contains_key(&PLAYER));
+    assert_eq!(physics.rigid_body_set.len(), 1);
+    assert_eq!(physics.collider_set.len(), 1);
+
+    // Adding the same player twice keeps a single body
+    add_player_at(&mut physics, 0.0);
+    assert_eq!(physics.rigid_body_set.len(), 1);
+
+    physics.remove_player(&PLAYER);
+    assert!(physics.players.is_empty());
+    assert_eq!(physics.rigid_body_set.len(), 0);
+    assert_eq!(physics.collider_set.len(), 0);
+
+    // Removing a missing player is a no-op
+    physics.remove_player(&PLAYER);
+}
+
+#[test]
+fn update_missing_player() {
+    let mut physics = Physics::<u32>::new();
+    assert!(physics
+        .update_player(&PLAYER, &input(true, false, false), yaw(0.0))
+        .is_none());
+}
+
+#[test]
+fn movement_follows_yaw() {
+    let mut physics = Physics::new();
+    add_player_at(&mut physics, 0.0);
+
+    let velocity = *physics
+        .update_player(&PLAYER, &input(true, false, false), yaw(0.0))
+        .unwrap()
+        .rigid_body
+        .linvel();
+    assert!((velocity.z - MOVEMENT_SPEED).abs() < 1e-5);
+    assert!(velocity.x.abs() < 1e-5);
+
+    // Facing +x after turning a quarter to the left
+    let velocity = *physics
+        .update_player(&PLAYER, &input(true, false, false), yaw(FRAC_PI_2))
+        .unwrap()
+        .rigid_body
+        .linvel();
+    assert!((velocity.x - MOVEMENT_SPEED).abs() < 1e-5);
+    assert!(velocity.z.abs() < 1e-5);
+
+    // Diagonal input is normalized so it isn't faster than walking straight
+    let velocity = *physics
+        .update_player(&PLAYER, &input(true, true, false), yaw(0.0))
+        .unwrap()
+        .rigid_body
+        .linvel();
+    assert!((velocity.xz().magnitude() - MOVEMENT_SPEED).abs() < 1e-5);
+    assert!(velocity.x > 0.0 && velocity.z > 0.0);
+
+    let velocity = *physics
+        .update_player(&PLAYER, &InputState::new(), yaw(0.0))
+        .unwrap()
+        .rigid_body
+        .linvel();
+    assert!(velocity.xz().magnitude() < 1e-5);
+}
+
+#[test]
+fn jump_only_when_grounded() {
+    // Nothing to stand on
+    let mut physics = Physics::new();
+    add_player_at(&mut physics, 5.0);
+    step(&mut physics, 2);
+    assert!(!physics.is_player_on_ground(&PLAYER));
+    let velocity = *physics
+        .update_player(&PLAYER, &input(false, false, true), yaw(0.0))
+        .unwrap()
+        .rigid_body
+        .linvel();
+    assert!(velocity.y <= 0.0);
+
+    // Standing on the floor
+    let mut physics = Physics::new();
+    add_floor(&mut physics);
+    add_player_at(&mut physics, 0.05);
+    step(&mut physics, 30);
+    assert!(physics.is_player_on_ground(&PLAYER));
+    let update = physics
+        .update_player(&PLAYER, &input(false, false, true), yaw(0.0))
+        .unwrap();
+    assert!(update.jumped);
+    assert!((update.rigid_body.linvel().y - JUMP_SPEED).abs() < 1e-5);
+
+    // In the air after jumping
+    step(&mut physics, 5);
+    assert!(!physics.is_player_on_ground(&PLAYER));
+}
+
+#[test]
+fn collides_with_uploaded_trimesh() {
+    let row = ColliderRow {
+        id: 7,
+        positions: vec![
+            DbVector3::new(-10.0, 0.0, -10.0),
+            DbVector3::new(10.0, 0.0, -10.0),
+            DbVector3::new(10.0, 0.0, 10.0),
+            DbVector3::new(-10.0, 0.0, 10.0),
+        ],
+        indices: vec![DbVector3::new(0.0, 2.0, 1.0), DbVector3::new(0.0, 3.0, 2.0)],
+        name: "floor".to_string(),
+        scene_version: 0,
+        material: SurfaceMaterial::new(SurfaceType::Grass),
+        volume: None,
+    };
+
+    let mut physics = Physics::new();
+    physics.add_collider(
+        row.id,
+        row.builder()
+            .unwrap()
+            .collision_groups(*SCENE_COLLISION_GROUP)
+            .build(),
+    );
+    add_player_at(&mut physics, 2.0);
+    step(&mut physics, 120);
+
+    assert!(player_body(&physics).translation().y > -0.1);
+    assert!(physics.is_player_on_ground(&PLAYER));
+    assert_eq!(
+        physics.player_ground_surface(&PLAYER),
+        Some(SurfaceType::Grass)
+    );
+
+    // Without the trimesh the player keeps falling
+    physics.remove_collider(row.id);
+    step(&mut physics, 60);
+    assert!(player_body(&physics).translation().y < -1.0);
+}
+
+#[test]
+fn snapshot_restores_the_simulation() {
+    let mut physics = Physics::new();
+    add_floor(&mut physics);
+    add_player_at(&mut physics, 0.05);
+    step(&mut physics, 30);
+    physics.update_player(&PLAYER, &input(true, false, false), yaw(0.0));
+    step(&mut physics, 1);
+
+    let mut restored = Physics::<u32>::restore(&physics.snapshot().unwrap()).unwrap();
+    assert_eq!(restored.players[&PLAYER], physics.players[&PLAYER]);
+    assert_eq!(restored.scene_colliders, physics.scene_colliders);
+    assert_eq!(
+        player_body(&restored).linvel(),
+        player_body(&physics).linvel()
+    );
+
+    // Both worlds keep simulating the same way
+    for _ in 0..30 {
+        physics.update_player(&PLAYER, &input(true, false, false), yaw(0.0));
+        restored.update_player(&PLAYER, &input(true, false, false), yaw(0.0));
+        step(&mut physics, 1);
+        step(&mut restored, 1);
+    }
+    assert_eq!(
+        player_body(&restored).translation(),
+        player_body(&physics).translation()
+    );
+    assert!(restored.is_player_on_ground(&PLAYER));
+}
+
+fn add_water(physics: &mut Physics<u32>) {
+    let points = [
+        Point::new(-10.0, -10.0, -10.0),
+        Point::new(10.0, -10.0, -10.0),
+        Point::new(10.0, -10.0, 10.0),
+        Point::new(-10.0, -10.0, 10.0),
+        Point::new(-10.0, 0.0, -10.0),
+        Point::new(10.0, 0.0, -10.0),
+        Point::new(10.0, 0.0, 10.0),
+        Point::new(-10.0, 0.0, 10.0),
+    ];
+    physics.add_volume(
+        1,
+        VolumeType::Water,
+        shared::collider::volume_builder(&points)
+            .unwrap()
+            .collision_groups(*SCENE_COLLISION_GROUP)
+            .build(),
+    );
+}
+
+#[test]
+fn swims_in_water() {
+    let mut physics = Physics::new();
+    add_water(&mut physics);
+    add_player_at(&mut physics, -5.0);
+    assert_eq!(
+        physics.player_movement_state(&PLAYER, &InputState::new()),
+        Some(MovementState::Swimming)
+    );
+
+    // Idle swimmers float up instead of sinking
+    for _ in 0..30 {
+        physics.update_player(&PLAYER, &InputState::new(), yaw(0.0));
+        step(&mut physics, 1);
+    }
+    assert!(player_body(&physics).translation().y > -5.0);
+
+    // Crouching dives down
+    let crouch = InputState {
+        crouch: true,
+        ..InputState::new()
+    };
+    let velocity = *physics
+        .update_player(&PLAYER, &crouch, yaw(0.0))
+        .unwrap()
+        .rigid_body
+        .linvel();
+    assert!(velocity.y < 0.0);
+
+    // Out of the water the player falls with full gravity again
+    physics.remove_collider(1);
+    assert_eq!(
+        physics.player_movement_state(&PLAYER, &InputState::new()),
+        Some(MovementState::Walking)
+    );
+    physics.update_player(&PLAYER, &InputState::new(), yaw(0.0));
+    assert_eq!(player_body(&physics).gravity_scale(), 1.0);
+}
+
+#[test]
+fn climbs_ladders() {
+    let mut physics = Physics::new();
+    add_floor(&mut physics);
+    // A thin ladder volume in front of the player, leaning slightly back
+    let points: Vec<_> = [-0.5, 0.5]
+        .iter()
+        .flat_map(|x| {
+            [(0.0, -0.5), (0.0, 0.5), (4.0, 0.5), (4.0, 1.5)].map(|(y, z)| Point::new(*x, y, z))
+        })
+        .collect();
+    physics.add_volume(
+        2,
+        VolumeType::Climbable,
+        shared::collider::volume_builder(&points)
+            .unwrap()
+            .collision_groups(*SCENE_COLLISION_GROUP)
+            .build(),
+    );
+    physics.add_player(PLAYER, Isometry::translation(0.0, 0.05, 0.0), false);
+    step(&mut physics, 10);
+
+    // Standing in the volume without pushing forward keeps walking
+    assert_eq!(
+        physics.player_movement_state(&PLAYER, &InputState::new()),
+        Some(MovementState::Walking)
+    );
+
+    let forward = input(true, false, false);
+    let Some(MovementState::Climbing(up)) = physics.player_movement_state(&PLAYER, &forward) else {
+        panic!("expected to climb");
+    };
+    assert!(up.y > 0.9 && up.z > 0.0);
+
+    let start = player_body(&physics).translation().y;
+    for _ in 0..10 {
+        physics.update_player(&PLAYER, &forward, yaw(0.0));
+        step(&mut physics, 1);
+    }
+    assert_eq!(player_body(&physics).gravity_scale(), 0.0);
+    assert!(player_body(&physics).translation().y > start + 0.2);
+}
+
+#[test]
+fn fly_and_noclip() {
+    let mut physics = Physics::new();
+    add_floor(&mut physics);
+    add_player_at(&mut physics, 0.05);
+    step(&mut physics, 10);
+
+    // Flying players float and rise with jump, which doesn't count as jumping
+    physics.set_player_movement_mode(&PLAYER, MovementMode::Fly, false);
+    assert!(physics.is_player_on_ground(&PLAYER));
+    assert!(
+        !physics
+            .update_player(&PLAYER, &input(false, false, true), yaw(0.0))
+            .unwrap()
+            .jumped
+    );
+    for _ in 0..30 {
+        physics.update_player(&PLAYER, &input(false, false, true), yaw(0.0));
+        step(&mut physics, 1);
+    }
+    assert_eq!(player_body(&physics).gravity_scale(), 0.0);
+    let height = player_body(&physics).translation().y;
+    assert!(height > 1.0);
+    for _ in 0..30 {
+        physics.update_player(&PLAYER, &InputState::new(), yaw(0.0));
+        step(&mut physics, 1);
+    }
+    assert!((player_body(&physics).translation().y - height).abs() < 0.05);
+
+    // Noclipping players sink through the floor when crouching
+    physics.set_player_movement_mode(&PLAYER, MovementMode::Noclip, false);
+    physics.set_player_collisions(&PLAYER, true);
+    let crouch = InputState {
+        crouch: true,
+        ..InputState::new()
+    };
+    for _ in 0..60 {
+        physics.update_player(&PLAYER, &crouch, yaw(0.0));
+        step(&mut physics, 1);
+    }
+    assert!(player_body(&physics).translation().y < -1.0);
+
+    // Walking again restores gravity and collisions
+    physics.set_player_movement_mode(&PLAYER, MovementMode::Walk, false);
+    physics.update_player(&PLAYER, &InputState::new(), yaw(0.0));
+    assert_eq!(player_body(&physics).gravity_scale(), 1.0);
+    let collider = player_body(&physics).colliders()[0];
+    assert_eq!(
+        physics.collider_set[collider].collision_groups(),
+        player_collision_groups(false)
+    );
+}
+
+#[test]
+fn point_query_finds_volumes() {
+    let mut physics = Physics::new();
+    add_water(&mut physics);
+    add_player_at(&mut physics, 5.0);
+    step(&mut physics, 1);
+    let underwater = Point::new(0.0, -5.0, 0.0);
+
+    // Volumes are sensors, only included when asked for
+    assert!(physics
+        .colliders_containing_point(underwater, Some(&PLAYER), false)
+        .is_empty());
+    let colliders = physics.colliders_containing_point(underwater, Some(&PLAYER), true);
+    assert_eq!(colliders.len(), 1);
+    assert_eq!(physics.volumes.get(&colliders[0]), Some(&VolumeType::Water));
+
+    assert!(physics
+        .colliders_containing_point(Point::new(0.0, 1.0, 0.0), Some(&PLAYER), true)
+        .is_empty());
+}
+
+#[test]
+fn point_query_inside_closed_trimesh() {
+    let (positions, indices) = Cuboid::new(Vector3::new(1.0, 1.0, 1.0)).to_trimesh();
+    let mut physics = Physics::<u32>::new();
+    physics.add_collider(
+        3,
+        shared::collider::mesh_builder(
+            positions,
+            indices,
+            &SurfaceMaterial::new(SurfaceType::Stone),
+            false,
+        )
+        .unwrap()
+        .collision_groups(*SCENE_COLLISION_GROUP)
+        .build(),
+    );
+    step(&mut physics, 1);
+
+    let colliders = physics.colliders_containing_point(Point::origin(), None, false);
+    assert_eq!(colliders.len(), 1);
+    assert_eq!(physics.scene_colliders.get(&colliders[0]), Some(&3));
+    assert!(physics
+        .colliders_containing_point(Point::new(0.0, 2.0, 0.0), None, false)
+        .is_empty());
+}
@@ -1,7 +1,15 @@
+use nalgebra::Unit;
 use rapier3d::prelude::*;
 
 pub fn is_on_ground(narrow_phase: &NarrowPhase, rigid_body: &RigidBody) -> bool {
-    let mut touching_ground = false;
+    ground_collider(narrow_phase, rigid_body).is_some()
+}
+
+// Find the collider the rigid body is standing on, if any
+pub fn ground_collider(
+    narrow_phase: &NarrowPhase,
+    rigid_body: &RigidBody,
+) -> Option<ColliderHandle> {
     let colliders = rigid_body.colliders();
     if !colliders.is_empty() {
         // TODO: Only one predefined collider for the player right now. Later, dynamically add colliders based on the player's model.
@@ -22,12 +30,55 @@ pub fn is_on_ground(narrow_phase: &NarrowPhase, rigid_body: &RigidBody) -> bool
                     };
 
                     if normal_y < -0.5 {
-                        touching_ground = true;
-                        break;
+                        return Some(if is_player_first {
+                            contact_pair.collider2
+                        } else {
+                            contact_pair.collider1
+                        });
                     }
                 }
             }
         }
     }
-    touching_ground
+    None
+}
+
+// Whether the point is inside the collider
+// Convex polyhedra are checked against their faces, their GJK based point query is unreliable deep inside
+pub fn contains_point(collider: &Collider, point: &Point<f32>) -> bool {
+    let Some(polyhedron) = collider.shape().as_convex_polyhedron() else {
+        return collider.shape().contains_point(collider.position(), point);
+    };
+
+    let local_point = collider.position().inverse_transform_point(point);
+    polyhedron.faces().iter().all(|face| {
+        let vertex = polyhedron.vertices_adj_to_face()[face.first_vertex_or_edge as usize];
+        (local_point - polyhedron.points()[vertex as usize]).dot(&face.normal) <= 0.0
+    })
+}
+
+// Direction players climb in a climbable volume
+// This is the main axis of the volume's hull that points up the most, so both ladders and walls work
+pub fn climb_direction(collider: &Collider) -> Unit<Vector<f32>> {
+    let Some(polyhedron) = collider.shape().as_convex_polyhedron() else {
+        return Vector::y_axis();
+    };
+
+    let points = polyhedron.points();
+    let center = points.iter().map(|point| point.coords).sum::<Vector<f32>>() / points.len() as f32;
+    let covariance = points
+        .iter()
+        .map(|point| (point.coords - center) * (point.coords - center).transpose())
+        .sum::<Matrix<f32>>();
+    let eigen = covariance.symmetric_eigen();
+
+    let axis = eigen
+        .eigenvectors
+        .column_iter()
+        .map(|axis| collider.rotation() * axis.into_owned())
+        .max_by(|a, b| a.y.abs().total_cmp(&b.y.abs()))
+        .unwrap_or(Vector::y());
+    let axis = if axis.y < 0.0 { -axis } else { axis };
+
+    Unit::try_new(axis, 1.0e-6).unwrap_or(Vector::y_axis())
 }
@@ -0,0 +1,85 @@
+use crate::moderation::{input_rate_limit, moderation_flag};
+use crate::player::logged_out_player;
+use crate::player::pose::tracked_pose;
+use crate::query::query_result;
+use crate::social::{block, friend_presence_result, friendship};
+use crate::stats::{leaderboard_result, player_stats, session};
+use crate::world::instance::instance_invite;
+use crate::world::portal::portal_cooldown;
+use crate::world::WorldSettings;
+use spacetimedb::{Identity, ReducerContext, Table};
+
+pub const CLEANUP_INTERVAL_MICROS: i64 = 60 * 60 * 1_000_000;
+
+#[spacetimedb::table(name = cleanup_schedule, scheduled(purge_logged_out_players))]
+pub struct CleanupSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub schedule_id: u64,
+
+    pub scheduled_at: spacetimedb::ScheduleAt,
+}
+
+// Remove guests that have been logged out for longer than the retention period, with all their data
+#[spacetimedb::reducer]
+pub fn purge_logged_out_players(ctx: &ReducerContext, _schedule: CleanupSchedule) {
+    if ctx.sender != ctx.identity() {
+        log::error!("Player {} is not authorized to purge players", ctx.sender);
+        return;
+    }
+
+    let retention = WorldSettings::get(ctx).logged_out_retention.to_micros();
+    let expired: Vec<_> = ctx
+        .db
+        .logged_out_player()
+        .iter()
+        .filter(|player| !player.persistent_profile)
+        .filter(|player| {
+            // Fall back to the last input for players without a logout time
+            let logged_out_at = player.logged_out_at.unwrap_or(player.last_input_at);
+            ctx.timestamp
+                .time_duration_since(logged_out_at)
+                .is_some_and(|duration| duration.to_micros() >= retention)
+        })
+        .map(|player| player.identity)
+        .collect();
+
+    for identity in &expired {
+        forget_identity(ctx, *identity);
+    }
+
+    if !expired.is_empty() {
+        log::info!("Purged {} logged out players", expired.len());
+    }
+}
+
+// Delete every row about a guest, except for bans, mutes and the audit log which moderation still needs
+fn forget_identity(ctx: &ReducerContext, identity: Identity) {
+    ctx.db.logged_out_player().identity().delete(identity);
+
+    ctx.db.session().identity().delete(identity);
+    ctx.db.player_stats().identity().delete(identity);
+    ctx.db.leaderboard_result().identity().delete(identity);
+
+    ctx.db.input_rate_limit().identity().delete(identity);
+    ctx.db.moderation_flag().identity().delete(identity);
+    ctx.db.portal_cooldown().identity().delete(identity);
+    ctx.db.tracked_pose().identity().delete(identity);
+    ctx.db.query_result().identity().delete(identity);
+    ctx.db.instance_invite().invitee().delete(identity);
+
+    ctx.db.friendship().sender().delete(identity);
+    ctx.db.friendship().receiver().delete(identity);
+    ctx.db.friend_presence_result().identity().delete(identity);
+    ctx.db.block().blocker().delete(identity);
+    let blocked_by: Vec<_> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|block| block.blocked == identity)
+        .map(|block| block.id)
+        .collect();
+    for id in blocked_by {
+        ctx.db.block().id().delete(id);
+    }
+}
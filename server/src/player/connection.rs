@@ -0,0 +1,39 @@
+use spacetimedb::{ConnectionId, Identity, ReducerContext, Table};
+
+// Clients currently connected to the module, an identity can be connected more than once
+#[spacetimedb::table(name = client_connection)]
+pub struct ClientConnection {
+    #[primary_key]
+    pub connection_id: ConnectionId,
+
+    #[index(btree)]
+    pub identity: Identity,
+}
+
+pub fn add_connection(ctx: &ReducerContext) {
+    if let Some(connection_id) = ctx.connection_id {
+        ctx.db.client_connection().insert(ClientConnection {
+            connection_id,
+            identity: ctx.sender,
+        });
+    }
+}
+
+pub fn remove_connection(ctx: &ReducerContext) {
+    if let Some(connection_id) = ctx.connection_id {
+        ctx.db
+            .client_connection()
+            .connection_id()
+            .delete(connection_id);
+    }
+}
+
+// Whether the identity still has a client connected
+pub fn is_connected(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db
+        .client_connection()
+        .identity()
+        .filter(identity)
+        .next()
+        .is_some()
+}
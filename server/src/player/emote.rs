@@ -0,0 +1,110 @@
+use crate::admin::ensure_admin;
+use crate::player::{player, Player};
+use spacetimedb::{ReducerContext, Table, TimeDuration};
+
+// Longest an emote is allowed to play for
+const MAX_EMOTE_DURATION_SECS: f32 = 30.0;
+
+// Emotes players can play, the name is used as the animation state on the clients
+#[spacetimedb::table(name = emote_catalog, public)]
+#[derive(Clone, Debug)]
+pub struct Emote {
+    #[primary_key]
+    pub name: String,
+
+    pub duration: TimeDuration,
+}
+
+fn duration_from_secs(secs: f32) -> TimeDuration {
+    TimeDuration::from_micros((secs * 1_000_000.0) as i64)
+}
+
+// Fill the catalog with the default emotes
+pub fn insert_default_emotes(ctx: &ReducerContext) {
+    for (name, secs) in [("wave", 2.0), ("dance", 8.0), ("point", 1.5), ("clap", 3.0)] {
+        if ctx
+            .db
+            .emote_catalog()
+            .name()
+            .find(name.to_string())
+            .is_none()
+        {
+            ctx.db.emote_catalog().insert(Emote {
+                name: name.to_string(),
+                duration: duration_from_secs(secs),
+            });
+        }
+    }
+}
+
+// Keep the player's emote playing, returns false once it expired or the player started moving
+pub fn update_emote(ctx: &ReducerContext, player: &mut Player) -> bool {
+    let Some(expires_at) = player.emote_expires_at else {
+        return false;
+    };
+
+    if ctx.timestamp >= expires_at || player.input.has_movement() {
+        player.emote_expires_at = None;
+        return false;
+    }
+    true
+}
+
+#[spacetimedb::reducer]
+pub fn play_emote(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    if player.seat_id.is_some() {
+        return Err("Can't emote while seated".to_string());
+    }
+
+    let emote = ctx
+        .db
+        .emote_catalog()
+        .name()
+        .find(&name)
+        .ok_or(format!("Emote {} not found", name))?;
+
+    player.animation_state = Some(emote.name);
+    player.emote_expires_at = Some(ctx.timestamp + emote.duration);
+    ctx.db.player().identity().update(player);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_emote(ctx: &ReducerContext, name: String, duration_secs: f32) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if name.is_empty() {
+        return Err("Emote name can't be empty".to_string());
+    }
+    if !(duration_secs > 0.0 && duration_secs <= MAX_EMOTE_DURATION_SECS) {
+        return Err(format!(
+            "Duration must be between 0 and {} seconds",
+            MAX_EMOTE_DURATION_SECS
+        ));
+    }
+
+    let emote = Emote {
+        name,
+        duration: duration_from_secs(duration_secs),
+    };
+    if ctx.db.emote_catalog().name().find(&emote.name).is_some() {
+        ctx.db.emote_catalog().name().update(emote);
+    } else {
+        ctx.db.emote_catalog().insert(emote);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_emote(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if !ctx.db.emote_catalog().name().delete(&name) {
+        return Err(format!("Emote {} not found", name));
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::physics::PHYSICS;
+use crate::player::{connection, log_out_player, player};
+use crate::world::instance::instance_physics;
+use crate::world::WorldSettings;
+use spacetimedb::{ReducerContext, Table, TimeDuration};
+
+pub const IDLE_CHECK_INTERVAL_MICROS: i64 = 10 * 1_000_000;
+
+#[spacetimedb::table(name = idle_check_schedule, scheduled(check_idle_players))]
+pub struct IdleCheckSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub schedule_id: u64,
+
+    pub scheduled_at: spacetimedb::ScheduleAt,
+}
+
+// What the idle check does with a player
+#[derive(Debug, PartialEq, Eq)]
+pub enum IdleAction {
+    Keep,
+    MarkAfk,
+    Evict,
+}
+
+// Connected players are only ever marked AFK, removing them would leave their client without a player
+// Only ghost players whose client went away without a clean disconnect get evicted
+pub fn idle_action(
+    idle: TimeDuration,
+    afk: bool,
+    connected: bool,
+    afk_timeout: TimeDuration,
+    evict_timeout: TimeDuration,
+) -> IdleAction {
+    if !connected && idle >= evict_timeout {
+        IdleAction::Evict
+    } else if !afk && idle >= afk_timeout {
+        IdleAction::MarkAfk
+    } else {
+        IdleAction::Keep
+    }
+}
+
+// Mark players without recent input as AFK and evict the ones that are gone
+#[spacetimedb::reducer]
+pub fn check_idle_players(ctx: &ReducerContext, _schedule: IdleCheckSchedule) {
+    if ctx.sender != ctx.identity() {
+        log::error!(
+            "Player {} is not authorized to check idle players",
+            ctx.sender
+        );
+        return;
+    }
+
+    let settings = WorldSettings::get(ctx);
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+
+    for mut player in ctx.db.player().iter() {
+        let idle = ctx
+            .timestamp
+            .time_duration_since(player.last_input_at)
+            .unwrap_or(TimeDuration::ZERO);
+        let connected = connection::is_connected(ctx, player.identity);
+
+        match idle_action(
+            idle,
+            player.afk,
+            connected,
+            settings.afk_timeout,
+            settings.evict_timeout,
+        ) {
+            IdleAction::Evict => {
+                log::info!("Evicting disconnected player {}", player.identity);
+                let physics = instance_physics(ctx, &mut worlds, player.instance_id);
+                log_out_player(ctx, physics, player);
+            }
+            IdleAction::MarkAfk => {
+                player.afk = true;
+                ctx.db.player().identity().update(player);
+            }
+            IdleAction::Keep => {}
+        }
+    }
+}
@@ -1,9 +1,26 @@
+pub mod cleanup;
+pub mod connection;
+pub mod emote;
+pub mod idle;
+pub mod pose;
 pub mod reducers;
 pub mod utils;
 
-use crate::math::DbVector3;
-use nalgebra::{Isometry3, Translation3, UnitQuaternion, Vector3};
-use spacetimedb::{ReducerContext, SpacetimeType};
+#[cfg(test)]
+mod tests;
+
+use crate::physics::Physics;
+use crate::stats;
+use crate::world::instance::Instance;
+use crate::world::{seat, WorldSettings};
+use nalgebra::{Isometry3, UnitQuaternion, Vector3};
+use serde::{Deserialize, Serialize};
+use shared::material::SurfaceType;
+use shared::math::{DbQuaternion, DbVector3};
+use spacetimedb::{ReducerContext, SpacetimeType, Table, Timestamp};
+
+// Upper bound for the personal space bubble radius
+pub const MAX_PERSONAL_SPACE_RADIUS: f32 = 5.0;
 
 #[spacetimedb::table(name = player, public)]
 #[spacetimedb::table(name = logged_out_player, public)]
@@ -24,12 +41,41 @@ pub struct Player {
 
     pub position: DbVector3,
     pub rotation_yaw: f32,
+    // Full orientation of the player's head / camera, including pitch and roll
+    pub look_rotation: DbQuaternion,
     pub animation_state: Option<String>,
+    // When the emote in `animation_state` ends, if one is playing
+    pub emote_expires_at: Option<Timestamp>,
     pub input: InputState,
+    // Surface the player is standing on, for footstep sounds
+    pub ground_surface: Option<SurfaceType>,
+
+    // Whether the player wants to collide with other players (if the world allows it)
+    pub collide_with_players: bool,
+    // Set by admins, e.g. to let world builders fly through the scene
+    pub movement_mode: MovementMode,
+    // Radius around the player in which other avatars are hidden by the client
+    // Disabled when not set
+    pub personal_space_radius: Option<f32>,
+
+    // The seat the player is currently sitting on
+    pub seat_id: Option<u32>,
+
+    // Last time the player sent any input, used for AFK detection
+    pub last_input_at: Timestamp,
+    pub afk: bool,
+
+    // Players with a persistent profile are never purged from `logged_out_player`
+    pub persistent_profile: bool,
+    pub logged_out_at: Option<Timestamp>,
+
+    // The instance of the world the player is in
+    #[index(btree)]
+    pub instance_id: u32,
 }
 
 impl Player {
-    pub fn new(ctx: &ReducerContext) -> Self {
+    pub fn new(ctx: &ReducerContext, instance: &Instance) -> Self {
         let color = utils::generate_random_hex_color(ctx);
         log::info!("Generated new color for new player: {}", color);
 
@@ -38,19 +84,95 @@ impl Player {
             player_id: 0,
             username: None,
             hex_color: Some(color),
-            position: DbVector3::new(0.0, 0.0, 0.0),
+            position: instance.spawn_point,
             rotation_yaw: 0.0,
+            look_rotation: DbQuaternion::identity(),
             animation_state: None,
+            emote_expires_at: None,
             input: InputState::new(),
+            ground_surface: None,
+            collide_with_players: false,
+            movement_mode: MovementMode::Walk,
+            personal_space_radius: None,
+            seat_id: None,
+            last_input_at: ctx.timestamp,
+            afk: false,
+            persistent_profile: false,
+            logged_out_at: None,
+            instance_id: instance.id,
         }
     }
 
+    // Whether the player's capsule should collide with other players
+    pub fn collides_with_players(&self, ctx: &ReducerContext) -> bool {
+        self.collide_with_players && WorldSettings::get(ctx).player_collisions
+    }
+
+    // Add the player's rigid body to the physics world
+    pub fn add_to_physics(&self, ctx: &ReducerContext, physics: &mut Physics) {
+        physics.add_player(
+            self.identity,
+            self.body_position(),
+            self.collides_with_players(ctx),
+        );
+        if self.movement_mode != MovementMode::Walk {
+            physics.set_player_movement_mode(
+                &self.identity,
+                self.movement_mode,
+                self.collides_with_players(ctx),
+            );
+        }
+    }
+
+    // Position of the player with the full look orientation, for head IK and raycasts
     pub fn position(&self) -> Isometry3<f32> {
+        Isometry3::from_parts(self.position.into(), self.look_rotation.into())
+    }
+
+    // Position of the player's body, which only ever rotates around the up axis
+    pub fn body_position(&self) -> Isometry3<f32> {
         Isometry3::from_parts(
-            Translation3::new(self.position.x, self.position.y, self.position.z),
+            self.position.into(),
             UnitQuaternion::from_axis_angle(&Vector3::y_axis(), self.rotation_yaw),
         )
     }
+
+    // Set the yaw, pitch and roll the player is looking at
+    pub fn set_look(&mut self, yaw: f32, pitch: f32, roll: f32) {
+        self.rotation_yaw = yaw;
+        self.look_rotation = (UnitQuaternion::from_axis_angle(&Vector3::y_axis(), yaw)
+            * UnitQuaternion::from_axis_angle(&Vector3::x_axis(), pitch)
+            * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), roll))
+        .into();
+    }
+}
+
+// Take the player out of the world, keeping their data in `logged_out_player`
+pub fn log_out_player(ctx: &ReducerContext, physics: &mut Physics, mut player: Player) {
+    player.logged_out_at = Some(ctx.timestamp);
+
+    // Free up the seat the player was sitting on
+    seat::release_seat(ctx, physics, &mut player);
+
+    // Remove the player from the physics world
+    physics.remove_player(&player.identity);
+
+    stats::end_session(ctx, player.identity);
+    pose::clear_pose(ctx, player.identity);
+
+    let identity = player.identity;
+    ctx.db.logged_out_player().insert(player);
+    ctx.db.player().identity().delete(identity);
+}
+
+// How the player's rigid body moves through the world
+#[derive(SpacetimeType, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovementMode {
+    Walk,
+    // No gravity, jump and crouch move up and down
+    Fly,
+    // Flying without colliding with anything
+    Noclip,
 }
 
 // Data structure that represents the player's input state
@@ -62,10 +184,23 @@ pub struct InputState {
     pub left: bool,
     pub right: bool,
     pub jump: bool,
+    // Moves down while swimming, climbing or flying
+    pub crouch: bool,
     pub is_pointer_locked: bool,
 }
 
+impl Default for InputState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl InputState {
+    // Whether the player is trying to move or jump
+    pub fn has_movement(&self) -> bool {
+        self.forward || self.backward || self.left || self.right || self.jump || self.crouch
+    }
+
     pub fn new() -> Self {
         Self {
             forward: false,
@@ -73,6 +208,7 @@ impl InputState {
             left: false,
             right: false,
             jump: false,
+            crouch: false,
             is_pointer_locked: false,
         }
     }
@@ -0,0 +1,190 @@
+use crate::player::player;
+use nalgebra::{Quaternion, UnitQuaternion, Vector3};
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+// Clients can't update their pose more often than the tick rate
+const MIN_POSE_INTERVAL_MICROS: i64 = 1_000_000 / 30;
+// Tracked positions are stored in millimeters relative to the player, limiting them to ~32m
+const POSITION_SCALE: f32 = 1000.0;
+// Curl values for the five fingers of both hands
+const MAX_FINGER_CURLS: usize = 10;
+
+// Range of the three smallest components of a unit quaternion
+const QUATERNION_COMPONENT_RANGE: f32 = std::f32::consts::FRAC_1_SQRT_2;
+const QUATERNION_COMPONENT_BITS: u32 = 10;
+const QUATERNION_COMPONENT_MAX: u32 = (1 << QUATERNION_COMPONENT_BITS) - 1;
+
+// Position and rotation of a tracked device, quantized to 10 bytes
+// The position is in millimeters relative to the player's position
+// The rotation uses the "smallest three" encoding: the index of the largest quaternion
+// component in the top two bits, followed by the other three components in 10 bits each
+#[derive(SpacetimeType, Debug, Clone, Copy)]
+pub struct QuantizedPose {
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+    pub rotation: u32,
+}
+
+impl QuantizedPose {
+    pub fn encode(position: Vector3<f32>, rotation: UnitQuaternion<f32>) -> Self {
+        let quantize = |v: f32| {
+            (v * POSITION_SCALE)
+                .round()
+                .clamp(i16::MIN as f32, i16::MAX as f32) as i16
+        };
+
+        Self {
+            x: quantize(position.x),
+            y: quantize(position.y),
+            z: quantize(position.z),
+            rotation: encode_rotation(rotation),
+        }
+    }
+
+    pub fn position(&self) -> Vector3<f32> {
+        Vector3::new(self.x as f32, self.y as f32, self.z as f32) / POSITION_SCALE
+    }
+
+    pub fn rotation(&self) -> UnitQuaternion<f32> {
+        decode_rotation(self.rotation)
+    }
+}
+
+fn encode_rotation(rotation: UnitQuaternion<f32>) -> u32 {
+    let components = rotation.coords;
+    let (largest, _) = components
+        .iter()
+        .enumerate()
+        .fold((0, 0.0), |(index, max), (i, v)| {
+            if v.abs() > max {
+                (i, v.abs())
+            } else {
+                (index, max)
+            }
+        });
+
+    // q and -q are the same rotation, so the largest component can always be made positive
+    let sign = if components[largest] < 0.0 { -1.0 } else { 1.0 };
+
+    let mut packed = (largest as u32) << (3 * QUATERNION_COMPONENT_BITS);
+    let mut shift = 2 * QUATERNION_COMPONENT_BITS;
+    for (i, v) in components.iter().enumerate() {
+        if i == largest {
+            continue;
+        }
+        let normalized = (v * sign / QUATERNION_COMPONENT_RANGE + 1.0) / 2.0;
+        let quantized = (normalized * QUATERNION_COMPONENT_MAX as f32)
+            .round()
+            .clamp(0.0, QUATERNION_COMPONENT_MAX as f32) as u32;
+        packed |= quantized << shift;
+        shift = shift.saturating_sub(QUATERNION_COMPONENT_BITS);
+    }
+    packed
+}
+
+fn decode_rotation(packed: u32) -> UnitQuaternion<f32> {
+    let largest = (packed >> (3 * QUATERNION_COMPONENT_BITS)) as usize;
+
+    let mut components = [0.0; 4];
+    let mut shift = 2 * QUATERNION_COMPONENT_BITS;
+    let mut sum = 0.0;
+    for (i, component) in components.iter_mut().enumerate() {
+        if i == largest {
+            continue;
+        }
+        let quantized = (packed >> shift) & QUATERNION_COMPONENT_MAX;
+        let v = (quantized as f32 / QUATERNION_COMPONENT_MAX as f32 * 2.0 - 1.0)
+            * QUATERNION_COMPONENT_RANGE;
+        *component = v;
+        sum += v * v;
+        shift = shift.saturating_sub(QUATERNION_COMPONENT_BITS);
+    }
+    components[largest] = (1.0 - sum).max(0.0).sqrt();
+
+    // nalgebra stores quaternions as [i, j, k, w]
+    UnitQuaternion::from_quaternion(Quaternion::new(
+        components[3],
+        components[0],
+        components[1],
+        components[2],
+    ))
+}
+
+// Head and hand poses of players using a VR headset
+// Players without a row here are animated from their position and yaw only
+#[spacetimedb::table(name = tracked_pose, public)]
+#[derive(Clone, Debug)]
+pub struct TrackedPose {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub head: QuantizedPose,
+    pub left_hand: Option<QuantizedPose>,
+    pub right_hand: Option<QuantizedPose>,
+    // Curl of each finger from 0 (straight) to 255 (fully curled), left hand first
+    pub finger_curls: Option<Vec<u8>>,
+
+    pub updated_at: Timestamp,
+}
+
+// Forget the tracked pose of a player leaving the world
+pub fn clear_pose(ctx: &ReducerContext, identity: Identity) {
+    ctx.db.tracked_pose().identity().delete(identity);
+}
+
+#[spacetimedb::reducer]
+pub fn update_tracked_pose(
+    ctx: &ReducerContext,
+    head: QuantizedPose,
+    left_hand: Option<QuantizedPose>,
+    right_hand: Option<QuantizedPose>,
+    finger_curls: Option<Vec<u8>>,
+) -> Result<(), String> {
+    if ctx.db.player().identity().find(ctx.sender).is_none() {
+        return Err("Player not found".to_string());
+    }
+    if finger_curls
+        .as_ref()
+        .is_some_and(|curls| curls.len() > MAX_FINGER_CURLS)
+    {
+        return Err(format!(
+            "At most {} finger curls are allowed",
+            MAX_FINGER_CURLS
+        ));
+    }
+
+    let pose = TrackedPose {
+        identity: ctx.sender,
+        head,
+        left_hand,
+        right_hand,
+        finger_curls,
+        updated_at: ctx.timestamp,
+    };
+
+    match ctx.db.tracked_pose().identity().find(ctx.sender) {
+        Some(previous) => {
+            // Drop updates sent faster than the server can use them
+            let elapsed = ctx
+                .timestamp
+                .time_duration_since(previous.updated_at)
+                .map_or(0, |duration| duration.to_micros());
+            if elapsed < MIN_POSE_INTERVAL_MICROS {
+                return Ok(());
+            }
+            ctx.db.tracked_pose().identity().update(pose);
+        }
+        None => {
+            ctx.db.tracked_pose().insert(pose);
+        }
+    }
+
+    Ok(())
+}
+
+// Stop syncing the tracked pose, e.g. when the player takes off their headset
+#[spacetimedb::reducer]
+pub fn clear_tracked_pose(ctx: &ReducerContext) {
+    clear_pose(ctx, ctx.sender);
+}
@@ -1,17 +1,40 @@
+use crate::admin::ensure_admin;
+use crate::moderation::{self, FlagKind};
 use crate::physics::PHYSICS;
-use crate::player::utils;
-use crate::player::{logged_out_player, player, InputState, Player};
-use spacetimedb::{ReducerContext, Table};
+use crate::player::{connection, utils};
+use crate::player::{
+    log_out_player, logged_out_player, player, InputState, MovementMode, Player,
+    MAX_PERSONAL_SPACE_RADIUS,
+};
+use crate::replay;
+use crate::stats;
+use crate::world::instance::{find_instance, instance_physics};
+use spacetimedb::{Identity, ReducerContext, Table};
 
 #[spacetimedb::reducer(client_connected)]
 pub fn connect(ctx: &ReducerContext) -> Result<(), String> {
-    let mut physics = PHYSICS.lock().expect("Failed to lock physics");
-    if let Some(player) = ctx.db.logged_out_player().identity().find(&ctx.sender) {
+    moderation::ensure_not_banned(ctx)?;
+
+    connection::add_connection(ctx);
+    stats::start_session(ctx);
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    if let Some(mut player) = ctx.db.player().identity().find(ctx.sender) {
+        // A ghost left behind by a client that crashed, or another client of the same identity
+        // Take over the player instead of waiting for the idle check to evict it
+        log::info!("Player {} took over their existing player", ctx.sender);
+        player.last_input_at = ctx.timestamp;
+        player.afk = false;
+
+        // Adding the body is a no-op when it is still in the physics world
+        player.add_to_physics(ctx, instance_physics(ctx, &mut worlds, player.instance_id));
+        ctx.db.player().identity().update(player);
+    } else if let Some(player) = ctx.db.logged_out_player().identity().find(ctx.sender) {
         // Make sure the player's color is preserved when reconnecting
         log::info!("Player reconnected with color: {:?}", player.hex_color);
 
         // If the player doesn't have a color, generate one now
-        let player = if player.hex_color.is_none() {
+        let mut player = if player.hex_color.is_none() {
             let color = utils::generate_random_hex_color(ctx);
             log::info!("Assigning new color to reconnecting player: {}", color);
 
@@ -21,21 +44,32 @@ pub fn connect(ctx: &ReducerContext) -> Result<(), String> {
         } else {
             player.clone()
         };
+        player.last_input_at = ctx.timestamp;
+        player.afk = false;
+        player.logged_out_at = None;
+
+        // Go back to the previous instance, or spawn in another one if it's gone or full
+        let instance = find_instance(ctx, ctx.sender, Some(player.instance_id))?;
+        if instance.id != player.instance_id {
+            player.instance_id = instance.id;
+            player.position = instance.spawn_point;
+        }
 
         ctx.db.player().insert(player.clone());
         ctx.db
             .logged_out_player()
             .identity()
-            .delete(&player.identity);
+            .delete(player.identity);
 
         // Add the player to the physics world
-        physics.add_player(&player);
+        player.add_to_physics(ctx, instance_physics(ctx, &mut worlds, player.instance_id));
     } else {
         // Create a new player
-        let player = Player::new(ctx);
+        let instance = find_instance(ctx, ctx.sender, None)?;
+        let player = Player::new(ctx, &instance);
 
         // Add the player to the physics world
-        physics.add_player(&player);
+        player.add_to_physics(ctx, instance_physics(ctx, &mut worlds, player.instance_id));
 
         ctx.db.player().try_insert(player)?;
     }
@@ -44,31 +78,160 @@ pub fn connect(ctx: &ReducerContext) -> Result<(), String> {
 
 #[spacetimedb::reducer(client_disconnected)]
 pub fn disconnect(ctx: &ReducerContext) -> Result<(), String> {
-    let mut physics = PHYSICS.lock().expect("Failed to lock physics");
-    let player = ctx
-        .db
-        .player()
-        .identity()
-        .find(&ctx.sender)
-        .ok_or("Player not found")?;
+    // Always forget the connection, even when the player was already removed (e.g. kicked)
+    connection::remove_connection(ctx);
 
-    // Remove the player from the physics world
-    physics.remove_player(&player);
+    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
+        log::info!("Disconnected {} was not in the world", ctx.sender);
+        return Ok(());
+    };
+    // Another client of the same identity took over the player
+    if connection::is_connected(ctx, ctx.sender) {
+        return Ok(());
+    }
 
-    //let player_id = player.player_id;
-    ctx.db.logged_out_player().insert(player);
-    ctx.db.player().identity().delete(&ctx.sender);
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    let physics = instance_physics(ctx, &mut worlds, player.instance_id);
+    log_out_player(ctx, physics, player);
 
     Ok(())
 }
 
+// `rotation` is the yaw of the player, `pitch` and `roll` only affect where they are looking
 #[spacetimedb::reducer]
-pub fn update_player_input(ctx: &ReducerContext, input: InputState, rotation: f32) {
-    if let Some(mut player) = ctx.db.player().identity().find(&ctx.sender) {
+pub fn update_player_input(
+    ctx: &ReducerContext,
+    input: InputState,
+    rotation: f32,
+    pitch: f32,
+    roll: f32,
+) {
+    // Rejected inputs are dropped instead of failing the reducer, so the flags are not rolled back
+    if !moderation::check_input_rate(ctx) {
+        moderation::flag(
+            ctx,
+            ctx.sender,
+            FlagKind::RateLimited,
+            format!(
+                "More than {} inputs per second",
+                moderation::MAX_INPUTS_PER_WINDOW
+            ),
+        );
+        return;
+    }
+
+    if ![rotation, pitch, roll].iter().all(|v| v.is_finite()) {
+        moderation::flag(
+            ctx,
+            ctx.sender,
+            FlagKind::InvalidInput,
+            format!("Invalid rotation {} {} {}", rotation, pitch, roll),
+        );
+        return;
+    }
+
+    if let Some(mut player) = ctx.db.player().identity().find(ctx.sender) {
+        replay::record_input(ctx, &player, input, rotation, pitch, roll);
+
         player.input = input;
-        player.rotation_yaw = rotation;
+        player.set_look(
+            utils::normalize_yaw(rotation),
+            utils::clamp_pitch(pitch),
+            utils::normalize_yaw(roll),
+        );
+        player.last_input_at = ctx.timestamp;
+        player.afk = false;
         ctx.db.player().identity().update(player);
     } else {
         log::error!("Player not found");
     }
 }
+
+#[spacetimedb::reducer]
+pub fn set_player_collisions(ctx: &ReducerContext, enabled: bool) -> Result<(), String> {
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+
+    player.collide_with_players = enabled;
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    instance_physics(ctx, &mut worlds, player.instance_id)
+        .set_player_collisions(&player.identity, player.collides_with_players(ctx));
+    ctx.db.player().identity().update(player);
+
+    Ok(())
+}
+
+// Let a player walk, fly or noclip through the world, e.g. to inspect the uploaded colliders
+#[spacetimedb::reducer]
+pub fn set_player_movement_mode(
+    ctx: &ReducerContext,
+    identity: Identity,
+    mode: MovementMode,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(identity)
+        .ok_or("Player not found")?;
+
+    player.movement_mode = mode;
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    instance_physics(ctx, &mut worlds, player.instance_id).set_player_movement_mode(
+        &player.identity,
+        mode,
+        player.collides_with_players(ctx),
+    );
+    ctx.db.player().identity().update(player);
+
+    log::info!(
+        "{} set the movement mode of {} to {:?}",
+        ctx.sender,
+        identity,
+        mode
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_personal_space(ctx: &ReducerContext, radius: Option<f32>) -> Result<(), String> {
+    if let Some(radius) = radius {
+        if !radius.is_finite() || radius <= 0.0 || radius > MAX_PERSONAL_SPACE_RADIUS {
+            return Err(format!(
+                "Personal space radius must be between 0 and {}",
+                MAX_PERSONAL_SPACE_RADIUS
+            ));
+        }
+    }
+
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    player.personal_space_radius = radius;
+    ctx.db.player().identity().update(player);
+
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_persistent_profile(ctx: &ReducerContext, enabled: bool) -> Result<(), String> {
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    player.persistent_profile = enabled;
+    ctx.db.player().identity().update(player);
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use super::idle::{idle_action, IdleAction};
+use super::pose::QuantizedPose;
+use nalgebra::{Quaternion, UnitQuaternion, Vector3};
+use spacetimedb::TimeDuration;
+
+fn minutes(minutes: i64) -> TimeDuration {
+    TimeDuration::from_micros(minutes * 60 * 1_000_000)
+}
+
+fn idle(idle: i64, afk: bool, connected: bool) -> IdleAction {
+    idle_action(minutes(idle), afk, connected, minutes(5), minutes(30))
+}
+
+#[test]
+fn idle_players_become_afk_then_get_evicted() {
+    assert_eq!(idle(1, false, true), IdleAction::Keep);
+    assert_eq!(idle(5, false, true), IdleAction::MarkAfk);
+    // Already marked players are left alone until they are evicted
+    assert_eq!(idle(10, true, true), IdleAction::Keep);
+    assert_eq!(idle(30, true, false), IdleAction::Evict);
+    // Ghosts that never got marked AFK are evicted right away
+    assert_eq!(idle(45, false, false), IdleAction::Evict);
+}
+
+#[test]
+fn connected_players_are_never_evicted() {
+    assert_eq!(idle(30, true, true), IdleAction::Keep);
+    assert_eq!(idle(24 * 60, false, true), IdleAction::MarkAfk);
+    // Disconnected players are kept until the eviction timeout
+    assert_eq!(idle(10, true, false), IdleAction::Keep);
+}
+
+// Largest rotation error of the smallest three encoding, in radians
+const MAX_ROTATION_ERROR: f32 = 0.005;
+
+fn round_trip(position: Vector3<f32>, rotation: UnitQuaternion<f32>) -> QuantizedPose {
+    QuantizedPose::encode(position, rotation)
+}
+
+#[test]
+fn pose_positions_round_trip_to_the_millimeter() {
+    for position in [
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(0.1234, 1.6789, -0.4321),
+        Vector3::new(-32.0, 32.0, 12.3456),
+    ] {
+        let pose = round_trip(position, UnitQuaternion::identity());
+        assert!((pose.position() - position).abs().max() <= 0.0005);
+    }
+}
+
+#[test]
+fn pose_positions_out_of_range_are_clamped() {
+    let pose = round_trip(Vector3::new(100.0, -100.0, 0.0), UnitQuaternion::identity());
+    assert_eq!((pose.x, pose.y, pose.z), (i16::MAX, i16::MIN, 0));
+    assert!(
+        (pose.position() - Vector3::new(32.767, -32.768, 0.0))
+            .abs()
+            .max()
+            < 1e-4
+    );
+}
+
+#[test]
+fn pose_rotations_round_trip_within_error_bound() {
+    for roll in -4..=4 {
+        for pitch in -4..=4 {
+            for yaw in -8..=8 {
+                let rotation = UnitQuaternion::from_euler_angles(
+                    roll as f32 * 0.39,
+                    pitch as f32 * 0.39,
+                    yaw as f32 * 0.39,
+                );
+                let decoded = round_trip(Vector3::zeros(), rotation).rotation();
+                assert!(
+                    rotation.angle_to(&decoded) < MAX_ROTATION_ERROR,
+                    "{:?} decoded as {:?}",
+                    rotation,
+                    decoded
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn pose_rotations_encode_each_largest_component() {
+    // nalgebra stores quaternions as [i, j, k, w], which is also the encoded index
+    for largest in 0..4 {
+        let mut coords = [0.1, -0.2, 0.3, -0.15];
+        coords[largest] = 0.9;
+        let rotation = UnitQuaternion::from_quaternion(Quaternion::new(
+            coords[3], coords[0], coords[1], coords[2],
+        ));
+
+        let pose = round_trip(Vector3::zeros(), rotation);
+        assert_eq!(pose.rotation >> 30, largest as u32);
+        assert!(rotation.angle_to(&pose.rotation()) < MAX_ROTATION_ERROR);
+    }
+}
+
+#[test]
+fn pose_rotations_encode_q_and_negated_q_the_same() {
+    for largest in 0..4 {
+        let mut coords = [0.2, 0.1, -0.3, 0.25];
+        coords[largest] = -0.85;
+        let rotation = UnitQuaternion::from_quaternion(Quaternion::new(
+            coords[3], coords[0], coords[1], coords[2],
+        ));
+        let negated = UnitQuaternion::new_unchecked(-rotation.into_inner());
+
+        let pose = round_trip(Vector3::zeros(), rotation);
+        assert_eq!(
+            pose.rotation,
+            round_trip(Vector3::zeros(), negated).rotation
+        );
+        // The largest component is decoded as positive, which is the same rotation
+        assert!(rotation.angle_to(&pose.rotation()) < MAX_ROTATION_ERROR);
+    }
+}
@@ -1,4 +1,6 @@
+use shared::math::DbColor;
 use spacetimedb::ReducerContext;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
 
 // Helper function to generate a random hex color using ReducerContext
 pub fn generate_random_hex_color(ctx: &ReducerContext) -> String {
@@ -7,6 +9,15 @@ pub fn generate_random_hex_color(ctx: &ReducerContext) -> String {
     let g: u8 = ctx.random::<u8>() % 224 + 32;
     let b: u8 = ctx.random::<u8>() % 224 + 32;
 
-    // Format as hex color string
-    format!("#{:02X}{:02X}{:02X}", r, g, b)
+    DbColor::from_rgb8(r, g, b).to_hex()
+}
+
+// Wrap a yaw angle into the [-PI, PI) range
+pub fn normalize_yaw(yaw: f32) -> f32 {
+    (yaw + PI).rem_euclid(TAU) - PI
+}
+
+// Keep the pitch between looking straight down and straight up
+pub fn clamp_pitch(pitch: f32) -> f32 {
+    pitch.clamp(-FRAC_PI_2, FRAC_PI_2)
 }
@@ -0,0 +1,74 @@
+pub mod reducers;
+
+use crate::physics::{query::QueryHit, Physics};
+use shared::math::DbVector3;
+use shared::volume::VolumeType;
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+// Maximum distance a ray or shape cast is allowed to travel
+pub const MAX_QUERY_DISTANCE: f32 = 100.0;
+// Maximum radius of the sphere used for shape casts
+pub const MAX_SHAPE_CAST_RADIUS: f32 = 5.0;
+
+// Latest scene query result of each caller
+// Clients pass a request id with every query so they can match the result to their request
+#[spacetimedb::table(name = query_result, public)]
+#[derive(Clone, Debug)]
+pub struct QueryResult {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub request_id: u32,
+    pub kind: QueryKind,
+    pub hits: Vec<SceneHit>,
+    pub timestamp: Timestamp,
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum QueryKind {
+    Raycast,
+    ShapeCast,
+    PointQuery,
+}
+
+// A hit against either an uploaded scene collider or another player
+#[derive(SpacetimeType, Debug, Clone)]
+pub struct SceneHit {
+    pub collider_id: Option<u32>,
+    pub player: Option<Identity>,
+    // Set when the collider is a water or climbable volume
+    pub volume: Option<VolumeType>,
+    pub point: DbVector3,
+    pub normal: DbVector3,
+    pub distance: f32,
+}
+
+impl SceneHit {
+    pub fn from_query_hit(physics: &Physics, hit: &QueryHit) -> Self {
+        Self {
+            collider_id: physics.scene_colliders.get(&hit.collider).copied(),
+            player: physics.player_for_collider(hit.collider),
+            volume: physics.volumes.get(&hit.collider).copied(),
+            point: hit.point.coords.into(),
+            normal: hit.normal.into(),
+            distance: hit.distance,
+        }
+    }
+}
+
+// Store the result of a query for the caller, replacing their previous result
+pub fn write_result(ctx: &ReducerContext, request_id: u32, kind: QueryKind, hits: Vec<SceneHit>) {
+    let result = QueryResult {
+        identity: ctx.sender,
+        request_id,
+        kind,
+        hits,
+        timestamp: ctx.timestamp,
+    };
+
+    if ctx.db.query_result().identity().find(ctx.sender).is_some() {
+        ctx.db.query_result().identity().update(result);
+    } else {
+        ctx.db.query_result().insert(result);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::physics::PHYSICS;
+use crate::player::player;
+use crate::query::{write_result, QueryKind, SceneHit, MAX_QUERY_DISTANCE, MAX_SHAPE_CAST_RADIUS};
+use crate::world::instance::instance_physics;
+use nalgebra::{Point3, Vector3};
+use shared::math::DbVector3;
+use spacetimedb::ReducerContext;
+
+fn validate_cast(direction: &Vector3<f32>, max_distance: f32) -> Result<(), String> {
+    if !direction.iter().all(|v| v.is_finite()) || direction.magnitude() == 0.0 {
+        return Err("Direction must be a finite, non-zero vector".to_string());
+    }
+    if !max_distance.is_finite() || max_distance <= 0.0 || max_distance > MAX_QUERY_DISTANCE {
+        return Err(format!(
+            "Max distance must be between 0 and {}",
+            MAX_QUERY_DISTANCE
+        ));
+    }
+    Ok(())
+}
+
+fn validate_point(point: &Point3<f32>) -> Result<(), String> {
+    if point.iter().all(|v| v.is_finite()) {
+        Ok(())
+    } else {
+        Err("Point must be finite".to_string())
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn raycast(
+    ctx: &ReducerContext,
+    request_id: u32,
+    origin: DbVector3,
+    direction: DbVector3,
+    max_distance: f32,
+) -> Result<(), String> {
+    let origin: Point3<f32> = origin.into();
+    let direction: Vector3<f32> = direction.into();
+    validate_point(&origin)?;
+    validate_cast(&direction, max_distance)?;
+
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    let physics = instance_physics(ctx, &mut worlds, player.instance_id);
+    let hits = physics
+        .cast_ray(origin, direction, max_distance, Some(&ctx.sender))
+        .map(|hit| SceneHit::from_query_hit(physics, &hit))
+        .into_iter()
+        .collect();
+
+    write_result(ctx, request_id, QueryKind::Raycast, hits);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn shape_cast(
+    ctx: &ReducerContext,
+    request_id: u32,
+    origin: DbVector3,
+    direction: DbVector3,
+    radius: f32,
+    max_distance: f32,
+) -> Result<(), String> {
+    let origin: Point3<f32> = origin.into();
+    let direction: Vector3<f32> = direction.into();
+    validate_point(&origin)?;
+    validate_cast(&direction, max_distance)?;
+    if !radius.is_finite() || radius <= 0.0 || radius > MAX_SHAPE_CAST_RADIUS {
+        return Err(format!(
+            "Radius must be between 0 and {}",
+            MAX_SHAPE_CAST_RADIUS
+        ));
+    }
+
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    let physics = instance_physics(ctx, &mut worlds, player.instance_id);
+    let hits = physics
+        .cast_sphere(origin, direction, radius, max_distance, Some(&ctx.sender))
+        .map(|hit| SceneHit::from_query_hit(physics, &hit))
+        .into_iter()
+        .collect();
+
+    write_result(ctx, request_id, QueryKind::ShapeCast, hits);
+    Ok(())
+}
+
+// Find the colliders containing a point, with `include_volumes` to also test water and climbable volumes
+#[spacetimedb::reducer]
+pub fn point_query(
+    ctx: &ReducerContext,
+    request_id: u32,
+    point: DbVector3,
+    include_volumes: bool,
+) -> Result<(), String> {
+    let point: Point3<f32> = point.into();
+    validate_point(&point)?;
+
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    let physics = instance_physics(ctx, &mut worlds, player.instance_id);
+    let hits = physics
+        .colliders_containing_point(point, Some(&ctx.sender), include_volumes)
+        .into_iter()
+        .map(|handle| SceneHit {
+            collider_id: physics.scene_colliders.get(&handle).copied(),
+            player: physics.player_for_collider(handle),
+            volume: physics.volumes.get(&handle).copied(),
+            point: point.coords.into(),
+            normal: DbVector3::new(0.0, 0.0, 0.0),
+            distance: 0.0,
+        })
+        .collect();
+
+    write_result(ctx, request_id, QueryKind::PointQuery, hits);
+    Ok(())
+}
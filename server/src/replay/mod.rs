@@ -0,0 +1,130 @@
+pub mod reducers;
+
+use crate::player::{InputState, Player};
+use crate::world::instance::instance;
+use crate::world::WorldSettings;
+use shared::math::DbVector3;
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+const SIMULATION_CLOCK_ID: u32 = 0;
+
+// Number of ticks simulated so far, there is only ever a single row in this table
+#[spacetimedb::table(name = simulation_clock)]
+#[derive(Clone, Debug)]
+pub struct SimulationClock {
+    #[primary_key]
+    pub id: u32,
+
+    pub tick: u64,
+    // Sequence number of the next recorded input
+    pub next_sequence: u64,
+}
+
+impl SimulationClock {
+    fn get(ctx: &ReducerContext) -> Self {
+        ctx.db
+            .simulation_clock()
+            .id()
+            .find(SIMULATION_CLOCK_ID)
+            .unwrap_or(Self {
+                id: SIMULATION_CLOCK_ID,
+                tick: 0,
+                next_sequence: 0,
+            })
+    }
+
+    fn save(self, ctx: &ReducerContext) {
+        if ctx.db.simulation_clock().id().find(self.id).is_some() {
+            ctx.db.simulation_clock().id().update(self);
+        } else {
+            ctx.db.simulation_clock().insert(self);
+        }
+    }
+}
+
+// An `update_player_input` call, kept in a ring buffer so simulations can be replayed
+// Private, since it holds the position of every player, only the database owner can subscribe to it
+#[spacetimedb::table(name = recorded_input)]
+#[derive(Clone, Debug)]
+pub struct RecordedInput {
+    // `sequence` modulo the buffer size, so the oldest inputs are overwritten
+    #[primary_key]
+    pub slot: u32,
+
+    pub sequence: u64,
+    // First tick that simulates with this input
+    pub tick: u64,
+    pub identity: Identity,
+    pub instance_id: u32,
+    pub scene_version: u32,
+    // Position of the player when the input was received
+    pub position: DbVector3,
+
+    pub input: InputState,
+    pub rotation: f32,
+    pub pitch: f32,
+    pub roll: f32,
+    pub timestamp: Timestamp,
+}
+
+// Advance the simulation clock, called at the start of every tick
+pub fn advance_tick(ctx: &ReducerContext) {
+    let mut clock = SimulationClock::get(ctx);
+    clock.tick += 1;
+    clock.save(ctx);
+}
+
+// Record an accepted input of the player if recording is enabled
+pub fn record_input(
+    ctx: &ReducerContext,
+    player: &Player,
+    input: InputState,
+    rotation: f32,
+    pitch: f32,
+    roll: f32,
+) {
+    let settings = WorldSettings::get(ctx);
+    if !settings.record_inputs || settings.input_buffer_size == 0 {
+        return;
+    }
+
+    let scene_version = ctx
+        .db
+        .instance()
+        .id()
+        .find(player.instance_id)
+        .map(|instance| instance.scene_version)
+        .unwrap_or(settings.scene_version);
+
+    let mut clock = SimulationClock::get(ctx);
+    let sequence = clock.next_sequence;
+    let record = RecordedInput {
+        slot: (sequence % settings.input_buffer_size as u64) as u32,
+        sequence,
+        // The input is only picked up by the next tick
+        tick: clock.tick + 1,
+        identity: player.identity,
+        instance_id: player.instance_id,
+        scene_version,
+        position: player.position,
+        input,
+        rotation,
+        pitch,
+        roll,
+        timestamp: ctx.timestamp,
+    };
+    clock.next_sequence += 1;
+    clock.save(ctx);
+
+    if ctx.db.recorded_input().slot().find(record.slot).is_some() {
+        ctx.db.recorded_input().slot().update(record);
+    } else {
+        ctx.db.recorded_input().insert(record);
+    }
+}
+
+pub fn clear_recorded_inputs(ctx: &ReducerContext) {
+    for record in ctx.db.recorded_input().iter() {
+        ctx.db.recorded_input().slot().delete(record.slot);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::admin::ensure_admin;
+use crate::replay::clear_recorded_inputs;
+use crate::world::WorldSettings;
+use spacetimedb::ReducerContext;
+
+const MAX_INPUT_BUFFER_SIZE: u32 = 100_000;
+
+#[spacetimedb::reducer]
+pub fn set_input_recording(
+    ctx: &ReducerContext,
+    enabled: bool,
+    buffer_size: u32,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if buffer_size == 0 || buffer_size > MAX_INPUT_BUFFER_SIZE {
+        return Err(format!(
+            "Buffer size must be between 1 and {}",
+            MAX_INPUT_BUFFER_SIZE
+        ));
+    }
+
+    let mut settings = WorldSettings::get(ctx);
+    // Slots are assigned modulo the buffer size, so a resized buffer starts over
+    if settings.input_buffer_size != buffer_size {
+        clear_recorded_inputs(ctx);
+    }
+    settings.record_inputs = enabled;
+    settings.input_buffer_size = buffer_size;
+    settings.save(ctx);
+
+    log::info!(
+        "{} set input recording to {} with {} slots",
+        ctx.sender,
+        enabled,
+        buffer_size
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn clear_input_recording(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    clear_recorded_inputs(ctx);
+    Ok(())
+}
@@ -0,0 +1,216 @@
+pub mod reducers;
+
+use crate::player::{logged_out_player, player};
+use spacetimedb::{
+    client_visibility_filter, Filter, Identity, ReducerContext, SpacetimeType, Table, Timestamp,
+};
+
+// Results older than this are left over from earlier queries and get cleared
+const FRIEND_PRESENCE_RESULT_TTL_MICROS: i64 = 60 * 1_000_000;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum FriendshipStatus {
+    // Waiting for the receiver to accept
+    Pending,
+    Accepted,
+}
+
+// Private, clients get their own friends through `get_friend_presence`
+#[spacetimedb::table(name = friendship)]
+#[derive(Clone, Debug)]
+pub struct Friendship {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    // The identity that sent the friend request
+    #[index(btree)]
+    pub sender: Identity,
+    #[index(btree)]
+    pub receiver: Identity,
+
+    pub status: FriendshipStatus,
+    pub created_at: Timestamp,
+    pub accepted_at: Option<Timestamp>,
+}
+
+impl Friendship {
+    // The other side of the friendship
+    pub fn other(&self, identity: Identity) -> Identity {
+        if self.sender == identity {
+            self.receiver
+        } else {
+            self.sender
+        }
+    }
+}
+
+// Users that are not allowed to send friend requests to the blocker or join them
+// Private and never published, so nobody can find out who blocked them
+#[spacetimedb::table(name = block)]
+#[derive(Clone, Debug)]
+pub struct Block {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub blocker: Identity,
+    pub blocked: Identity,
+    pub created_at: Timestamp,
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum PresenceStatus {
+    Online,
+    Afk,
+    Offline,
+}
+
+#[derive(SpacetimeType, Debug, Clone)]
+pub struct FriendPresence {
+    pub identity: Identity,
+    pub status: PresenceStatus,
+    // The instance the friend is in while online
+    pub instance_id: Option<u32>,
+    // When the friend logged out, if they are offline
+    pub last_seen: Option<Timestamp>,
+}
+
+// Presence of the caller's friends and their pending requests, written by `get_friend_presence`
+#[spacetimedb::table(name = friend_presence_result, public)]
+#[derive(Clone, Debug)]
+pub struct FriendPresenceResult {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub friends: Vec<FriendPresence>,
+    // Users waiting for the caller to accept their request
+    pub incoming_requests: Vec<Identity>,
+    // Users the caller sent a request to
+    pub outgoing_requests: Vec<Identity>,
+    pub timestamp: Timestamp,
+}
+
+// Each client only sees its own result, the friend lists of others stay hidden
+#[client_visibility_filter]
+const FRIEND_PRESENCE_RESULT_VISIBILITY: Filter =
+    Filter::Sql("SELECT * FROM friend_presence_result WHERE identity = :sender");
+
+// Replace the caller's result, and clear the stale results of other callers
+pub fn write_friend_presence_result(ctx: &ReducerContext, result: FriendPresenceResult) {
+    let stale: Vec<_> = ctx
+        .db
+        .friend_presence_result()
+        .iter()
+        .filter(|result| {
+            result.identity == ctx.sender
+                || ctx
+                    .timestamp
+                    .time_duration_since(result.timestamp)
+                    .is_some_and(|age| age.to_micros() >= FRIEND_PRESENCE_RESULT_TTL_MICROS)
+        })
+        .map(|result| result.identity)
+        .collect();
+    for identity in stale {
+        ctx.db.friend_presence_result().identity().delete(identity);
+    }
+
+    ctx.db.friend_presence_result().insert(result);
+}
+
+// Friendship between two identities in either direction, whatever its status
+pub fn find_friendship(ctx: &ReducerContext, a: Identity, b: Identity) -> Option<Friendship> {
+    ctx.db
+        .friendship()
+        .sender()
+        .filter(a)
+        .find(|friendship| friendship.receiver == b)
+        .or_else(|| {
+            ctx.db
+                .friendship()
+                .sender()
+                .filter(b)
+                .find(|friendship| friendship.receiver == a)
+        })
+}
+
+pub fn are_friends(ctx: &ReducerContext, a: Identity, b: Identity) -> bool {
+    find_friendship(ctx, a, b)
+        .is_some_and(|friendship| friendship.status == FriendshipStatus::Accepted)
+}
+
+// Accepted friends of the identity
+pub fn friends(ctx: &ReducerContext, identity: Identity) -> Vec<Identity> {
+    ctx.db
+        .friendship()
+        .sender()
+        .filter(identity)
+        .chain(ctx.db.friendship().receiver().filter(identity))
+        .filter(|friendship| friendship.status == FriendshipStatus::Accepted)
+        .map(|friendship| friendship.other(identity))
+        .collect()
+}
+
+// Users with a pending friend request to the identity
+pub fn incoming_requests(ctx: &ReducerContext, identity: Identity) -> Vec<Identity> {
+    ctx.db
+        .friendship()
+        .receiver()
+        .filter(identity)
+        .filter(|friendship| friendship.status == FriendshipStatus::Pending)
+        .map(|friendship| friendship.sender)
+        .collect()
+}
+
+// Users the identity sent a pending friend request to
+pub fn outgoing_requests(ctx: &ReducerContext, identity: Identity) -> Vec<Identity> {
+    ctx.db
+        .friendship()
+        .sender()
+        .filter(identity)
+        .filter(|friendship| friendship.status == FriendshipStatus::Pending)
+        .map(|friendship| friendship.receiver)
+        .collect()
+}
+
+pub fn has_blocked(ctx: &ReducerContext, blocker: Identity, blocked: Identity) -> bool {
+    ctx.db
+        .block()
+        .blocker()
+        .filter(blocker)
+        .any(|block| block.blocked == blocked)
+}
+
+// Whether either identity blocked the other
+pub fn is_blocked_between(ctx: &ReducerContext, a: Identity, b: Identity) -> bool {
+    has_blocked(ctx, a, b) || has_blocked(ctx, b, a)
+}
+
+// Presence of an identity, based on whether they are in `player` or `logged_out_player`
+pub fn presence(ctx: &ReducerContext, identity: Identity) -> FriendPresence {
+    if let Some(player) = ctx.db.player().identity().find(identity) {
+        return FriendPresence {
+            identity,
+            status: if player.afk {
+                PresenceStatus::Afk
+            } else {
+                PresenceStatus::Online
+            },
+            instance_id: Some(player.instance_id),
+            last_seen: None,
+        };
+    }
+
+    FriendPresence {
+        identity,
+        status: PresenceStatus::Offline,
+        instance_id: None,
+        last_seen: ctx
+            .db
+            .logged_out_player()
+            .identity()
+            .find(identity)
+            .and_then(|player| player.logged_out_at),
+    }
+}
@@ -0,0 +1,178 @@
+use crate::moderation;
+use crate::physics::PHYSICS;
+use crate::player::player;
+use crate::social::{
+    are_friends, block, find_friendship, friends, friendship, has_blocked, incoming_requests,
+    is_blocked_between, outgoing_requests, presence, write_friend_presence_result, Block,
+    FriendPresenceResult, Friendship, FriendshipStatus,
+};
+use crate::world::instance::{instance as db_instance, move_player};
+use spacetimedb::{Identity, ReducerContext, Table};
+
+#[spacetimedb::reducer]
+pub fn send_friend_request(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    if identity == ctx.sender {
+        return Err("You can't befriend yourself".to_string());
+    }
+    if is_blocked_between(ctx, ctx.sender, identity) {
+        return Err("Unable to send a friend request to this user".to_string());
+    }
+
+    match find_friendship(ctx, ctx.sender, identity) {
+        // Sending a request to someone that already sent one accepts theirs
+        Some(friendship)
+            if friendship.status == FriendshipStatus::Pending
+                && friendship.receiver == ctx.sender =>
+        {
+            accept(ctx, friendship);
+            Ok(())
+        }
+        Some(friendship) if friendship.status == FriendshipStatus::Pending => {
+            Err("Friend request already sent".to_string())
+        }
+        Some(_) => Err("Already friends".to_string()),
+        None => {
+            ctx.db.friendship().insert(Friendship {
+                id: 0,
+                sender: ctx.sender,
+                receiver: identity,
+                status: FriendshipStatus::Pending,
+                created_at: ctx.timestamp,
+                accepted_at: None,
+            });
+            Ok(())
+        }
+    }
+}
+
+fn accept(ctx: &ReducerContext, mut friendship: Friendship) {
+    friendship.status = FriendshipStatus::Accepted;
+    friendship.accepted_at = Some(ctx.timestamp);
+    ctx.db.friendship().id().update(friendship);
+}
+
+#[spacetimedb::reducer]
+pub fn accept_friend_request(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    let friendship = find_friendship(ctx, identity, ctx.sender)
+        .filter(|friendship| {
+            friendship.status == FriendshipStatus::Pending && friendship.receiver == ctx.sender
+        })
+        .ok_or("Friend request not found")?;
+
+    accept(ctx, friendship);
+    Ok(())
+}
+
+// Remove a friend, or decline / cancel a pending friend request
+#[spacetimedb::reducer]
+pub fn remove_friend(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    let friendship = find_friendship(ctx, ctx.sender, identity).ok_or("Friendship not found")?;
+    ctx.db.friendship().id().delete(friendship.id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn block_user(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    if identity == ctx.sender {
+        return Err("You can't block yourself".to_string());
+    }
+    if has_blocked(ctx, ctx.sender, identity) {
+        return Err("User is already blocked".to_string());
+    }
+
+    // Blocking someone also ends the friendship
+    if let Some(friendship) = find_friendship(ctx, ctx.sender, identity) {
+        ctx.db.friendship().id().delete(friendship.id);
+    }
+
+    ctx.db.block().insert(Block {
+        id: 0,
+        blocker: ctx.sender,
+        blocked: identity,
+        created_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unblock_user(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    let block = ctx
+        .db
+        .block()
+        .blocker()
+        .filter(ctx.sender)
+        .find(|block| block.blocked == identity)
+        .ok_or("User is not blocked")?;
+
+    ctx.db.block().id().delete(block.id);
+    Ok(())
+}
+
+// The friendship and block tables are private, this is how clients see their own friends
+#[spacetimedb::reducer]
+pub fn get_friend_presence(ctx: &ReducerContext) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    let result = FriendPresenceResult {
+        identity: ctx.sender,
+        friends: friends(ctx, ctx.sender)
+            .into_iter()
+            .map(|friend| presence(ctx, friend))
+            .collect(),
+        incoming_requests: incoming_requests(ctx, ctx.sender),
+        outgoing_requests: outgoing_requests(ctx, ctx.sender),
+        timestamp: ctx.timestamp,
+    };
+
+    write_friend_presence_result(ctx, result);
+
+    Ok(())
+}
+
+// Move the caller into the instance their friend is in
+#[spacetimedb::reducer]
+pub fn join_friend(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    if !are_friends(ctx, ctx.sender, identity) {
+        return Err("You are not friends with this user".to_string());
+    }
+
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    let friend = ctx
+        .db
+        .player()
+        .identity()
+        .find(identity)
+        .ok_or("Friend is not online")?;
+    if player.instance_id == friend.instance_id {
+        return Err("Already in the same instance".to_string());
+    }
+
+    let instance = ctx
+        .db
+        .instance()
+        .id()
+        .find(friend.instance_id)
+        .ok_or("Instance not found")?;
+    instance.ensure_can_join(ctx, ctx.sender)?;
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    move_player(
+        ctx,
+        &mut worlds,
+        &mut player,
+        &instance,
+        instance.spawn_point,
+    );
+    ctx.db.player().identity().update(player);
+
+    Ok(())
+}
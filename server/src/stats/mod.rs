@@ -0,0 +1,150 @@
+pub mod reducers;
+
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, TimeDuration, Timestamp};
+
+// A single visit of a player, from connect to disconnect
+#[spacetimedb::table(name = session, public)]
+#[derive(Clone, Debug)]
+pub struct Session {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub identity: Identity,
+    pub connected_at: Timestamp,
+    // Not set while the session is still running
+    pub disconnected_at: Option<Timestamp>,
+}
+
+// Lifetime statistics of a player, kept across sessions
+#[spacetimedb::table(name = player_stats, public)]
+#[derive(Clone, Debug)]
+pub struct PlayerStats {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub session_count: u32,
+    // Play time of all finished sessions
+    pub total_play_time: TimeDuration,
+    pub distance_travelled: f64,
+    pub jump_count: u32,
+}
+
+impl PlayerStats {
+    fn new(identity: Identity) -> Self {
+        Self {
+            identity,
+            session_count: 0,
+            total_play_time: TimeDuration::from_micros(0),
+            distance_travelled: 0.0,
+            jump_count: 0,
+        }
+    }
+
+    fn get(ctx: &ReducerContext, identity: Identity) -> Self {
+        ctx.db
+            .player_stats()
+            .identity()
+            .find(identity)
+            .unwrap_or_else(|| Self::new(identity))
+    }
+
+    fn save(self, ctx: &ReducerContext) {
+        if ctx
+            .db
+            .player_stats()
+            .identity()
+            .find(self.identity)
+            .is_some()
+        {
+            ctx.db.player_stats().identity().update(self);
+        } else {
+            ctx.db.player_stats().insert(self);
+        }
+    }
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum LeaderboardMetric {
+    PlayTime,
+    Distance,
+    Jumps,
+}
+
+#[derive(SpacetimeType, Debug, Clone)]
+pub struct LeaderboardEntry {
+    pub identity: Identity,
+    pub value: f64,
+}
+
+// Latest leaderboard requested by each caller
+#[spacetimedb::table(name = leaderboard_result, public)]
+#[derive(Clone, Debug)]
+pub struct LeaderboardResult {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub metric: LeaderboardMetric,
+    pub entries: Vec<LeaderboardEntry>,
+    pub timestamp: Timestamp,
+}
+
+fn open_session(ctx: &ReducerContext, identity: Identity) -> Option<Session> {
+    ctx.db
+        .session()
+        .identity()
+        .filter(identity)
+        .find(|session| session.disconnected_at.is_none())
+}
+
+// Open a new session for the connecting player
+pub fn start_session(ctx: &ReducerContext) {
+    // A previous session might not have been closed if the module was restarted
+    end_session(ctx, ctx.sender);
+
+    ctx.db.session().insert(Session {
+        id: 0,
+        identity: ctx.sender,
+        connected_at: ctx.timestamp,
+        disconnected_at: None,
+    });
+
+    let mut stats = PlayerStats::get(ctx, ctx.sender);
+    stats.session_count += 1;
+    stats.save(ctx);
+}
+
+// Close the running session of the player and add it to their play time
+pub fn end_session(ctx: &ReducerContext, identity: Identity) {
+    let Some(mut session) = open_session(ctx, identity) else {
+        return;
+    };
+
+    let duration = ctx
+        .timestamp
+        .time_duration_since(session.connected_at)
+        .unwrap_or(TimeDuration::from_micros(0));
+    session.disconnected_at = Some(ctx.timestamp);
+    ctx.db.session().id().update(session);
+
+    let mut stats = PlayerStats::get(ctx, identity);
+    stats.total_play_time =
+        TimeDuration::from_micros(stats.total_play_time.to_micros() + duration.to_micros());
+    stats.save(ctx);
+}
+
+// Accumulate the movement of a player during a tick
+pub fn record_movement(ctx: &ReducerContext, identity: Identity, distance: f32, jumped: bool) {
+    // Ignore tiny position jitter so we don't write the stats of idle players every tick
+    if distance < 0.001 && !jumped {
+        return;
+    }
+
+    let mut stats = PlayerStats::get(ctx, identity);
+    stats.distance_travelled += distance as f64;
+    if jumped {
+        stats.jump_count += 1;
+    }
+    stats.save(ctx);
+}
@@ -0,0 +1,56 @@
+use crate::stats::{
+    leaderboard_result, player_stats, LeaderboardEntry, LeaderboardMetric, LeaderboardResult,
+};
+use spacetimedb::{ReducerContext, Table};
+
+const MAX_LEADERBOARD_SIZE: u32 = 100;
+
+#[spacetimedb::reducer]
+pub fn get_leaderboard(
+    ctx: &ReducerContext,
+    metric: LeaderboardMetric,
+    limit: u32,
+) -> Result<(), String> {
+    if limit == 0 || limit > MAX_LEADERBOARD_SIZE {
+        return Err(format!(
+            "Limit must be between 1 and {}",
+            MAX_LEADERBOARD_SIZE
+        ));
+    }
+
+    let mut entries: Vec<LeaderboardEntry> = ctx
+        .db
+        .player_stats()
+        .iter()
+        .map(|stats| LeaderboardEntry {
+            identity: stats.identity,
+            value: match metric {
+                LeaderboardMetric::PlayTime => stats.total_play_time.to_micros() as f64 / 1e6,
+                LeaderboardMetric::Distance => stats.distance_travelled,
+                LeaderboardMetric::Jumps => stats.jump_count as f64,
+            },
+        })
+        .collect();
+    entries.sort_by(|a, b| b.value.total_cmp(&a.value));
+    entries.truncate(limit as usize);
+
+    let result = LeaderboardResult {
+        identity: ctx.sender,
+        metric,
+        entries,
+        timestamp: ctx.timestamp,
+    };
+    if ctx
+        .db
+        .leaderboard_result()
+        .identity()
+        .find(ctx.sender)
+        .is_some()
+    {
+        ctx.db.leaderboard_result().identity().update(result);
+    } else {
+        ctx.db.leaderboard_result().insert(result);
+    }
+
+    Ok(())
+}
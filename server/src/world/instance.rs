@@ -0,0 +1,479 @@
+use crate::admin::is_admin;
+use crate::moderation;
+use crate::physics::{Physics, PhysicsWorlds, PHYSICS, SCENE_COLLISION_GROUP};
+use crate::player::{player, Player};
+use crate::social;
+use crate::world::platform::platform;
+use crate::world::portal::portal as db_portal;
+use crate::world::seat::seat as db_seat;
+use crate::world::snapshot::physics_snapshot;
+use crate::world::{collider, portal, scene_spawn, seat, snapshot, WorldSettings};
+use shared::math::DbVector3;
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+pub const MAX_INSTANCE_CAPACITY: u32 = 64;
+// Number of rooms a single user can create
+pub const MAX_ROOMS_PER_OWNER: usize = 3;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum InstanceVisibility {
+    // Anyone can join
+    Public,
+    // Only friends of the owner and invited users can join
+    FriendsOnly,
+    // Only invited users can join
+    InviteOnly,
+}
+
+// A copy of a scene version with its own physics world and set of players
+#[spacetimedb::table(name = instance, public)]
+#[derive(Clone, Debug)]
+pub struct Instance {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    pub name: String,
+    pub scene_version: u32,
+    pub capacity: u32,
+    pub spawn_point: DbVector3,
+    pub owner: Identity,
+    pub created_at: Timestamp,
+    pub visibility: InstanceVisibility,
+}
+
+// Permission for a user to join a non-public instance
+#[spacetimedb::table(name = instance_invite, public)]
+#[derive(Clone, Debug)]
+pub struct InstanceInvite {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub instance_id: u32,
+    #[index(btree)]
+    pub invitee: Identity,
+    pub invited_by: Identity,
+    pub created_at: Timestamp,
+}
+
+impl Instance {
+    pub fn player_count(&self, ctx: &ReducerContext) -> u32 {
+        ctx.db.player().instance_id().filter(self.id).count() as u32
+    }
+
+    pub fn is_full(&self, ctx: &ReducerContext) -> bool {
+        self.player_count(ctx) >= self.capacity
+    }
+
+    pub fn is_invited(&self, ctx: &ReducerContext, identity: Identity) -> bool {
+        ctx.db
+            .instance_invite()
+            .invitee()
+            .filter(identity)
+            .any(|invite| invite.instance_id == self.id)
+    }
+
+    // Check whether the user is allowed into the instance and there is room for them
+    pub fn ensure_can_join(&self, ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+        if identity != self.owner {
+            if social::has_blocked(ctx, self.owner, identity) {
+                return Err("You are not allowed to join this instance".to_string());
+            }
+
+            let allowed = match self.visibility {
+                InstanceVisibility::Public => true,
+                InstanceVisibility::FriendsOnly => {
+                    social::are_friends(ctx, self.owner, identity) || self.is_invited(ctx, identity)
+                }
+                InstanceVisibility::InviteOnly => self.is_invited(ctx, identity),
+            };
+            if !allowed {
+                return Err("This instance is private".to_string());
+            }
+        }
+
+        if self.is_full(ctx) {
+            return Err("Instance is full".to_string());
+        }
+        Ok(())
+    }
+}
+
+// Only the owner of an instance and admins can manage it
+fn ensure_instance_owner(ctx: &ReducerContext, instance: &Instance) -> Result<(), String> {
+    if instance.owner == ctx.sender || is_admin(ctx, ctx.sender) {
+        Ok(())
+    } else {
+        Err("Only the owner can manage this instance".to_string())
+    }
+}
+
+pub fn validate_capacity(capacity: u32) -> Result<(), String> {
+    if capacity == 0 || capacity > MAX_INSTANCE_CAPACITY {
+        return Err(format!(
+            "Capacity must be between 1 and {}",
+            MAX_INSTANCE_CAPACITY
+        ));
+    }
+    Ok(())
+}
+
+// Build the physics world of an instance from the colliders of its scene version
+fn load_physics(ctx: &ReducerContext, instance_id: u32) -> Physics {
+    let mut physics = Physics::new();
+    let Some(instance) = ctx.db.instance().id().find(instance_id) else {
+        log::error!("Instance {} not found", instance_id);
+        return physics;
+    };
+
+    // Continue from the last snapshot, e.g. after the module was republished
+    if let Some(physics) = snapshot::restore_physics(ctx, &instance) {
+        return physics;
+    }
+
+    for collider in ctx
+        .db
+        .collider()
+        .scene_version()
+        .filter(instance.scene_version)
+    {
+        let Some(platform) = ctx.db.platform().collider_id().find(collider.id) else {
+            collider.add_to_physics(&mut physics);
+            continue;
+        };
+        if let Some(builder) = collider.builder() {
+            physics.add_platform(
+                platform.id,
+                collider.id,
+                builder.collision_groups(*SCENE_COLLISION_GROUP).build(),
+            );
+        }
+    }
+
+    portal::load_portals(ctx, &mut physics, instance_id);
+
+    // Players can already be in the instance if the physics worlds were lost (e.g. on a module update)
+    for player in ctx.db.player().instance_id().filter(instance_id) {
+        player.add_to_physics(ctx, &mut physics);
+    }
+
+    log::info!("Loaded physics world of instance {}", instance_id);
+    physics
+}
+
+// Get the physics world of an instance, loading it if it isn't in memory yet
+pub fn instance_physics<'a>(
+    ctx: &ReducerContext,
+    worlds: &'a mut PhysicsWorlds,
+    instance_id: u32,
+) -> &'a mut Physics {
+    worlds
+        .entry(instance_id)
+        .or_insert_with(|| load_physics(ctx, instance_id))
+}
+
+// Physics worlds of the loaded instances using the given scene version
+pub fn scene_physics<'a>(
+    ctx: &'a ReducerContext,
+    worlds: &'a mut PhysicsWorlds,
+    scene_version: u32,
+) -> impl Iterator<Item = &'a mut Physics> {
+    worlds
+        .iter_mut()
+        .filter(move |(instance_id, _)| {
+            ctx.db
+                .instance()
+                .id()
+                .find(**instance_id)
+                .is_some_and(|instance| instance.scene_version == scene_version)
+        })
+        .map(|(_, physics)| physics)
+}
+
+// Where players appear in new instances of the scene version
+pub fn spawn_point(ctx: &ReducerContext, scene_version: u32) -> DbVector3 {
+    ctx.db
+        .scene_spawn()
+        .scene_version()
+        .find(scene_version)
+        .map_or(DbVector3::new(0.0, 0.0, 0.0), |spawn| spawn.position)
+}
+
+// Instances created automatically are owned by the module, rooms by the user who created them
+pub fn create_instance(
+    ctx: &ReducerContext,
+    owner: Identity,
+    name: String,
+    scene_version: u32,
+    capacity: u32,
+    visibility: InstanceVisibility,
+) -> Result<Instance, String> {
+    validate_capacity(capacity)?;
+    Ok(ctx.db.instance().try_insert(Instance {
+        id: 0,
+        name,
+        scene_version,
+        capacity,
+        spawn_point: spawn_point(ctx, scene_version),
+        owner,
+        created_at: ctx.timestamp,
+        visibility,
+    })?)
+}
+
+// Pick the instance a player is placed in, e.g. when connecting
+// Players go back to the instance they left if they can still join it, otherwise into the first
+// public instance of the default scene with room, and a new instance is created when all of them are full
+pub fn find_instance(
+    ctx: &ReducerContext,
+    identity: Identity,
+    previous: Option<u32>,
+) -> Result<Instance, String> {
+    if let Some(instance) = previous.and_then(|id| ctx.db.instance().id().find(id)) {
+        if instance.ensure_can_join(ctx, identity).is_ok() {
+            return Ok(instance);
+        }
+    }
+
+    let settings = WorldSettings::get(ctx);
+    let mut instances: Vec<Instance> = ctx
+        .db
+        .instance()
+        .iter()
+        .filter(|instance| {
+            instance.scene_version == settings.scene_version
+                && instance.visibility == InstanceVisibility::Public
+        })
+        .collect();
+    instances.sort_by_key(|instance| instance.id);
+
+    match instances
+        .into_iter()
+        .find(|instance| !instance.is_full(ctx))
+    {
+        Some(instance) => Ok(instance),
+        None => {
+            let count = ctx.db.instance().count();
+            create_instance(
+                ctx,
+                ctx.identity(),
+                format!("Instance {}", count + 1),
+                settings.scene_version,
+                settings.instance_capacity,
+                InstanceVisibility::Public,
+            )
+        }
+    }
+}
+
+// Move a player from their current instance to the given position in another instance
+pub fn move_player(
+    ctx: &ReducerContext,
+    worlds: &mut PhysicsWorlds,
+    player: &mut Player,
+    instance: &Instance,
+    position: DbVector3,
+) {
+    let physics = instance_physics(ctx, worlds, player.instance_id);
+    seat::release_seat(ctx, physics, player);
+    physics.remove_player(&player.identity);
+
+    player.instance_id = instance.id;
+    player.position = position;
+    player.add_to_physics(ctx, instance_physics(ctx, worlds, instance.id));
+}
+
+#[spacetimedb::reducer]
+pub fn create_room(
+    ctx: &ReducerContext,
+    name: String,
+    scene_version: u32,
+    capacity: u32,
+    visibility: InstanceVisibility,
+) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    let rooms = ctx
+        .db
+        .instance()
+        .iter()
+        .filter(|instance| instance.owner == ctx.sender)
+        .count();
+    if rooms >= MAX_ROOMS_PER_OWNER && !is_admin(ctx, ctx.sender) {
+        return Err(format!(
+            "You can't own more than {} rooms",
+            MAX_ROOMS_PER_OWNER
+        ));
+    }
+
+    let instance = create_instance(ctx, ctx.sender, name, scene_version, capacity, visibility)?;
+    log::info!("{} created instance {}", ctx.sender, instance.id);
+    Ok(())
+}
+
+// Delete a room, the players inside are moved to another instance first
+#[spacetimedb::reducer]
+pub fn delete_room(ctx: &ReducerContext, instance_id: u32) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    let instance = ctx
+        .db
+        .instance()
+        .id()
+        .find(instance_id)
+        .ok_or("Instance not found")?;
+    ensure_instance_owner(ctx, &instance)?;
+
+    // Delete the row first so the occupants can't be placed back into the room
+    ctx.db.instance().id().delete(instance.id);
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    let occupants: Vec<Player> = ctx.db.player().instance_id().filter(instance.id).collect();
+    for mut player in occupants {
+        let destination = find_instance(ctx, player.identity, None)?;
+        move_player(
+            ctx,
+            &mut worlds,
+            &mut player,
+            &destination,
+            destination.spawn_point,
+        );
+        ctx.db.player().identity().update(player);
+    }
+    worlds.remove(&instance.id);
+
+    for seat in ctx.db.seat().instance_id().filter(instance.id) {
+        ctx.db.seat().id().delete(seat.id);
+    }
+    for portal in ctx.db.portal().instance_id().filter(instance.id) {
+        ctx.db.portal().id().delete(portal.id);
+    }
+    // Portals elsewhere that lead into the room
+    let entrances: Vec<_> = ctx
+        .db
+        .portal()
+        .iter()
+        .filter(|portal| portal.destination_instance_id == instance.id)
+        .collect();
+    for portal in entrances {
+        if let Some(physics) = worlds.get_mut(&portal.instance_id) {
+            physics.remove_portal(portal.id);
+        }
+        ctx.db.portal().id().delete(portal.id);
+    }
+    for invite in ctx.db.instance_invite().instance_id().filter(instance.id) {
+        ctx.db.instance_invite().id().delete(invite.id);
+    }
+    ctx.db.physics_snapshot().instance_id().delete(instance.id);
+
+    log::info!("{} deleted instance {}", ctx.sender, instance.id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn join_instance(ctx: &ReducerContext, instance_id: u32) -> Result<(), String> {
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    if player.instance_id == instance_id {
+        return Err("Player is already in this instance".to_string());
+    }
+
+    let instance = ctx
+        .db
+        .instance()
+        .id()
+        .find(instance_id)
+        .ok_or("Instance not found")?;
+    instance.ensure_can_join(ctx, ctx.sender)?;
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    move_player(
+        ctx,
+        &mut worlds,
+        &mut player,
+        &instance,
+        instance.spawn_point,
+    );
+    ctx.db.player().identity().update(player);
+
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_instance_visibility(
+    ctx: &ReducerContext,
+    instance_id: u32,
+    visibility: InstanceVisibility,
+) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    let mut instance = ctx
+        .db
+        .instance()
+        .id()
+        .find(instance_id)
+        .ok_or("Instance not found")?;
+    ensure_instance_owner(ctx, &instance)?;
+
+    // Players already inside stay, the visibility only applies to players joining afterwards
+    instance.visibility = visibility;
+    ctx.db.instance().id().update(instance);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn invite_to_instance(
+    ctx: &ReducerContext,
+    instance_id: u32,
+    identity: Identity,
+) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    let instance = ctx
+        .db
+        .instance()
+        .id()
+        .find(instance_id)
+        .ok_or("Instance not found")?;
+    ensure_instance_owner(ctx, &instance)?;
+    if instance.is_invited(ctx, identity) {
+        return Err("User is already invited".to_string());
+    }
+
+    ctx.db.instance_invite().insert(InstanceInvite {
+        id: 0,
+        instance_id,
+        invitee: identity,
+        invited_by: ctx.sender,
+        created_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn revoke_invite(
+    ctx: &ReducerContext,
+    instance_id: u32,
+    identity: Identity,
+) -> Result<(), String> {
+    moderation::ensure_not_banned(ctx)?;
+    let instance = ctx
+        .db
+        .instance()
+        .id()
+        .find(instance_id)
+        .ok_or("Instance not found")?;
+    ensure_instance_owner(ctx, &instance)?;
+
+    let invite = ctx
+        .db
+        .instance_invite()
+        .invitee()
+        .filter(identity)
+        .find(|invite| invite.instance_id == instance_id)
+        .ok_or("Invite not found")?;
+    ctx.db.instance_invite().id().delete(invite.id);
+    Ok(())
+}
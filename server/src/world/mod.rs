@@ -1,7 +1,20 @@
-use crate::math::DbVector3;
-use crate::physics::{PHYSICS, SCENE_COLLISION_GROUP};
-use rapier3d::{parry::transformation::convex_hull, prelude::ColliderBuilder};
-use spacetimedb::{ReducerContext, Table};
+pub mod instance;
+pub mod platform;
+pub mod portal;
+pub mod seat;
+pub mod snapshot;
+pub mod volume;
+
+use crate::admin::ensure_admin;
+use crate::physics::{Physics, PHYSICS, SCENE_COLLISION_GROUP};
+use crate::player::player;
+use instance::{instance as db_instance, instance_physics, scene_physics};
+use rapier3d::prelude::ColliderBuilder;
+use shared::collider::mesh_builder;
+use shared::material::SurfaceMaterial;
+use shared::math::DbVector3;
+use shared::volume::VolumeType;
+use spacetimedb::{ReducerContext, Table, TimeDuration};
 
 #[spacetimedb::table(name = collider, public)]
 #[derive(Clone, Debug)]
@@ -13,24 +26,142 @@ pub struct Collider {
     pub positions: Vec<DbVector3>,
     pub indices: Vec<DbVector3>,
     pub name: String,
+
+    // Version of the scene this collider was uploaded for
+    #[index(btree)]
+    pub scene_version: u32,
+
+    pub material: SurfaceMaterial,
+    // Set when the collider is a volume (e.g. water) instead of a solid surface
+    pub volume: Option<VolumeType>,
+}
+
+impl Collider {
+    // Build the collider for this row, in world space
+    pub fn builder(&self) -> Option<ColliderBuilder> {
+        mesh_builder(
+            self.positions.iter().map(|point| (*point).into()).collect(),
+            self.indices
+                .iter()
+                .map(|indices| (*indices).into())
+                .collect(),
+            &self.material,
+            self.volume.is_some(),
+        )
+    }
+
+    // Add this row to a physics world as a static collider or volume
+    pub fn add_to_physics(&self, physics: &mut Physics) {
+        let Some(builder) = self.builder() else {
+            log::error!("Collider {} has an invalid mesh", self.id);
+            return;
+        };
+        let collider = builder.collision_groups(*SCENE_COLLISION_GROUP).build();
+
+        match self.volume {
+            Some(volume) => physics.add_volume(self.id, volume, collider),
+            None => physics.add_collider(self.id, collider),
+        }
+    }
+}
+
+// Where players appear in new instances of a scene version, the origin when it isn't set
+#[spacetimedb::table(name = scene_spawn, public)]
+#[derive(Clone, Debug)]
+pub struct SceneSpawn {
+    #[primary_key]
+    pub scene_version: u32,
+
+    pub position: DbVector3,
+}
+
+const WORLD_SETTINGS_ID: u32 = 0;
+
+// Global settings of the world, there is only ever a single row in this table
+#[spacetimedb::table(name = world_settings, public)]
+#[derive(Clone, Debug)]
+pub struct WorldSettings {
+    #[primary_key]
+    pub id: u32,
+
+    // Whether players are allowed to collide with each other at all
+    // Players still have to opt in themselves before they collide
+    pub player_collisions: bool,
+
+    // How long a player can go without input before being marked AFK
+    pub afk_timeout: TimeDuration,
+    // How long a player whose client is gone stays in the world before being removed
+    pub evict_timeout: TimeDuration,
+    // How long guests without a persistent profile are kept in `logged_out_player`
+    pub logged_out_retention: TimeDuration,
+
+    // Scene version and capacity used for automatically created instances
+    pub scene_version: u32,
+    pub instance_capacity: u32,
+
+    // Whether every player input is recorded into `recorded_input` for replays
+    pub record_inputs: bool,
+    // Number of inputs kept before the oldest ones are overwritten
+    pub input_buffer_size: u32,
 }
 
+impl WorldSettings {
+    fn new() -> Self {
+        Self {
+            id: WORLD_SETTINGS_ID,
+            player_collisions: false,
+            afk_timeout: TimeDuration::from_micros(5 * 60 * 1_000_000),
+            evict_timeout: TimeDuration::from_micros(30 * 60 * 1_000_000),
+            logged_out_retention: TimeDuration::from_micros(30 * 24 * 60 * 60 * 1_000_000),
+            scene_version: 0,
+            instance_capacity: 16,
+            record_inputs: false,
+            input_buffer_size: 10_000,
+        }
+    }
+
+    pub fn get(ctx: &ReducerContext) -> Self {
+        ctx.db
+            .world_settings()
+            .id()
+            .find(WORLD_SETTINGS_ID)
+            .unwrap_or_else(Self::new)
+    }
+
+    pub fn save(self, ctx: &ReducerContext) {
+        if ctx.db.world_settings().id().find(self.id).is_some() {
+            ctx.db.world_settings().id().update(self);
+        } else {
+            ctx.db.world_settings().insert(self);
+        }
+    }
+}
+
+// Scene geometry is uploaded by the glTF uploader, which connects with the token of an admin
 #[spacetimedb::reducer]
 pub fn upload_body(
     ctx: &ReducerContext,
     points: Vec<DbVector3>,
     indices: Vec<DbVector3>,
     name: String,
+    scene_version: u32,
+    material: SurfaceMaterial,
+    volume: Option<VolumeType>,
 ) -> Result<(), String> {
+    ensure_admin(ctx)?;
     log::info!("Uploading body with {} points", points.len());
+    material.validate()?;
 
-    let mut physics = PHYSICS.lock().expect("Failed to lock physics");
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
 
-    ctx.db.collider().try_insert(Collider {
+    let collider = ctx.db.collider().try_insert(Collider {
         id: 0,
-        positions: points.clone(),
+        positions: points,
         name,
-        indices: indices.clone(),
+        indices,
+        scene_version,
+        material,
+        volume,
     })?;
 
     // let ch = convex_hull(&positions);
@@ -40,18 +171,154 @@ pub fn upload_body(
     //     physics.add_collider(builder.collision_groups(*SCENE_COLLISION_GROUP).build());
     // }
 
-    let mut positions = Vec::new();
-    for point in points {
-        positions.push(rapier3d::prelude::Point::new(point.x, point.y, point.z));
+    // Add the collider to every loaded instance of this scene version
+    for physics in scene_physics(ctx, &mut worlds, scene_version) {
+        collider.add_to_physics(physics);
+    }
+
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_collider_material(
+    ctx: &ReducerContext,
+    collider_id: u32,
+    material: SurfaceMaterial,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    material.validate()?;
+
+    let mut collider = ctx
+        .db
+        .collider()
+        .id()
+        .find(collider_id)
+        .ok_or("Collider not found")?;
+    collider.material = material;
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    for physics in scene_physics(ctx, &mut worlds, collider.scene_version) {
+        physics.set_collider_material(collider.id, &material);
+    }
+    ctx.db.collider().id().update(collider);
+
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_scene_spawn_point(
+    ctx: &ReducerContext,
+    scene_version: u32,
+    position: DbVector3,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if !position.is_finite() {
+        return Err("Spawn point must be finite".to_string());
+    }
+
+    let spawn = SceneSpawn {
+        scene_version,
+        position,
+    };
+    if ctx
+        .db
+        .scene_spawn()
+        .scene_version()
+        .find(scene_version)
+        .is_some()
+    {
+        ctx.db.scene_spawn().scene_version().update(spawn);
+    } else {
+        ctx.db.scene_spawn().insert(spawn);
+    }
+
+    // Existing instances of the scene use the new spawn point from now on
+    for mut instance in ctx.db.instance().iter() {
+        if instance.scene_version == scene_version {
+            instance.spawn_point = position;
+            ctx.db.instance().id().update(instance);
+        }
     }
-    let mut new_indices: Vec<[u32; 3]> = Vec::new();
-    for index in indices {
-        new_indices.push([index.x as u32, index.y as u32, index.z as u32]);
+
+    log::info!(
+        "{} set the spawn point of scene version {} to {:?}",
+        ctx.sender,
+        scene_version,
+        position
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_world_player_collisions(ctx: &ReducerContext, enabled: bool) -> Result<(), String> {
+    ensure_admin(ctx)?;
+
+    let mut settings = WorldSettings::get(ctx);
+    settings.player_collisions = enabled;
+    settings.save(ctx);
+
+    // Re-apply the collision groups of everyone currently in the world
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    for player in ctx.db.player().iter() {
+        instance_physics(ctx, &mut worlds, player.instance_id)
+            .set_player_collisions(&player.identity, enabled && player.collide_with_players);
+    }
+
+    log::info!("{} set world player collisions to {}", ctx.sender, enabled);
+    Ok(())
+}
+
+// Settings take `u32` seconds, which always fit in a `TimeDuration`
+fn duration_from_secs(secs: u32) -> TimeDuration {
+    TimeDuration::from_micros(i64::from(secs) * 1_000_000)
+}
+
+#[spacetimedb::reducer]
+pub fn set_idle_timeouts(
+    ctx: &ReducerContext,
+    afk_timeout_secs: u32,
+    evict_timeout_secs: u32,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if afk_timeout_secs == 0 || evict_timeout_secs < afk_timeout_secs {
+        return Err("The eviction timeout must be at least as long as the AFK timeout".to_string());
     }
 
-    if let Ok(builder) = ColliderBuilder::trimesh(positions, new_indices) {
-        physics.add_collider(builder.collision_groups(*SCENE_COLLISION_GROUP).build());
+    let mut settings = WorldSettings::get(ctx);
+    settings.afk_timeout = duration_from_secs(afk_timeout_secs);
+    settings.evict_timeout = duration_from_secs(evict_timeout_secs);
+    settings.save(ctx);
+
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_logged_out_retention(ctx: &ReducerContext, retention_secs: u32) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if retention_secs == 0 {
+        return Err("Retention must be longer than zero seconds".to_string());
     }
 
+    let mut settings = WorldSettings::get(ctx);
+    settings.logged_out_retention = duration_from_secs(retention_secs);
+    settings.save(ctx);
+
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_default_scene(
+    ctx: &ReducerContext,
+    scene_version: u32,
+    instance_capacity: u32,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    instance::validate_capacity(instance_capacity)?;
+
+    let mut settings = WorldSettings::get(ctx);
+    settings.scene_version = scene_version;
+    settings.instance_capacity = instance_capacity;
+    settings.save(ctx);
+
     Ok(())
 }
@@ -0,0 +1,201 @@
+use crate::admin::ensure_admin;
+use crate::physics::{PhysicsWorlds, PHYSICS, SCENE_COLLISION_GROUP};
+use crate::world::collider;
+use crate::world::instance::scene_physics;
+use nalgebra::Vector3;
+use shared::math::DbVector3;
+use spacetimedb::{ReducerContext, SpacetimeType, Table, Timestamp};
+
+// A scene collider that moves along a looping keyframe path (moving platforms, elevators, ...)
+#[spacetimedb::table(name = platform, public)]
+#[derive(Clone, Debug)]
+pub struct Platform {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[unique]
+    pub collider_id: u32,
+
+    // Offsets from the uploaded position of the collider, sorted by time
+    pub keyframes: Vec<PlatformKeyframe>,
+    pub started_at: Timestamp,
+
+    // Current offset of the platform, updated every tick so clients can move the mesh
+    pub translation: DbVector3,
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy)]
+pub struct PlatformKeyframe {
+    // Seconds since the start of the loop
+    pub time: f32,
+    pub translation: DbVector3,
+}
+
+impl Platform {
+    // Length of a single loop in seconds
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |keyframe| keyframe.time)
+    }
+
+    // Linearly interpolate the keyframes at the given time, looping after the last keyframe
+    pub fn translation_at(&self, seconds: f32) -> Vector3<f32> {
+        let duration = self.duration();
+        let time = if duration > 0.0 {
+            seconds % duration
+        } else {
+            0.0
+        };
+
+        let Some(first) = self.keyframes.first() else {
+            return Vector3::zeros();
+        };
+
+        let mut previous = first;
+        for keyframe in &self.keyframes {
+            if keyframe.time >= time {
+                let span = keyframe.time - previous.time;
+                let t = if span > 0.0 {
+                    (time - previous.time) / span
+                } else {
+                    1.0
+                };
+                let from: Vector3<f32> = previous.translation.into();
+                let to: Vector3<f32> = keyframe.translation.into();
+                return from.lerp(&to, t);
+            }
+            previous = keyframe;
+        }
+
+        previous.translation.into()
+    }
+}
+
+fn validate_keyframes(keyframes: &[PlatformKeyframe]) -> Result<(), String> {
+    if keyframes.is_empty() {
+        return Err("A platform needs at least one keyframe".to_string());
+    }
+
+    let mut last_time = 0.0;
+    for keyframe in keyframes {
+        let translation = keyframe.translation;
+        if !keyframe.time.is_finite()
+            || ![translation.x, translation.y, translation.z]
+                .iter()
+                .all(|v| v.is_finite())
+        {
+            return Err("Keyframes must be finite".to_string());
+        }
+        if keyframe.time < last_time {
+            return Err("Keyframes must be sorted by time".to_string());
+        }
+        last_time = keyframe.time;
+    }
+
+    Ok(())
+}
+
+// Move every platform to its position for the current tick
+pub fn update_platforms(ctx: &ReducerContext, worlds: &mut PhysicsWorlds) {
+    for mut platform in ctx.db.platform().iter() {
+        let seconds = ctx
+            .timestamp
+            .duration_since(platform.started_at)
+            .map_or(0.0, |duration| duration.as_secs_f32());
+        let translation = platform.translation_at(seconds);
+
+        for physics in worlds.values_mut() {
+            physics.move_platform(platform.id, translation);
+        }
+        platform.translation = translation.into();
+        ctx.db.platform().id().update(platform);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn create_platform(
+    ctx: &ReducerContext,
+    collider_name: String,
+    scene_version: u32,
+    keyframes: Vec<PlatformKeyframe>,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    validate_keyframes(&keyframes)?;
+
+    // Node names are only unique within a single version of the scene
+    let mut colliders = ctx
+        .db
+        .collider()
+        .scene_version()
+        .filter(scene_version)
+        .filter(|collider| collider.name == collider_name);
+    let collider = colliders.next().ok_or(format!(
+        "Collider {} not found in scene version {}",
+        collider_name, scene_version
+    ))?;
+    if colliders.next().is_some() {
+        return Err(format!("Multiple colliders are named {}", collider_name));
+    }
+
+    let builder = collider
+        .builder()
+        .ok_or(format!("Collider {} has an invalid mesh", collider_name))?;
+
+    let platform = ctx.db.platform().try_insert(Platform {
+        id: 0,
+        collider_id: collider.id,
+        keyframes,
+        started_at: ctx.timestamp,
+        translation: DbVector3::new(0.0, 0.0, 0.0),
+    })?;
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    for physics in scene_physics(ctx, &mut worlds, collider.scene_version) {
+        physics.add_platform(
+            platform.id,
+            collider.id,
+            builder
+                .clone()
+                .collision_groups(*SCENE_COLLISION_GROUP)
+                .build(),
+        );
+    }
+
+    log::info!("Created platform {} from {}", platform.id, collider_name);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_platform(ctx: &ReducerContext, platform_id: u32) -> Result<(), String> {
+    ensure_admin(ctx)?;
+
+    let platform = ctx
+        .db
+        .platform()
+        .id()
+        .find(platform_id)
+        .ok_or("Platform not found")?;
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    for physics in worlds.values_mut() {
+        physics.remove_platform(platform.id);
+    }
+
+    // Put the collider back in its uploaded position
+    if let Some(collider) = ctx.db.collider().id().find(platform.collider_id) {
+        if let Some(builder) = collider.builder() {
+            for physics in scene_physics(ctx, &mut worlds, collider.scene_version) {
+                physics.add_collider(
+                    collider.id,
+                    builder
+                        .clone()
+                        .collision_groups(*SCENE_COLLISION_GROUP)
+                        .build(),
+                );
+            }
+        }
+    }
+
+    ctx.db.platform().id().delete(platform.id);
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use crate::admin::ensure_admin;
+use crate::physics::{Physics, PhysicsWorlds, PHYSICS, SCENE_COLLISION_GROUP};
+use crate::player::player;
+use crate::world::instance::{instance as db_instance, move_player};
+use rapier3d::prelude::{Collider, ColliderBuilder};
+use shared::math::DbVector3;
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+// Time a player has to wait after going through a portal before another one picks them up
+// Prevents players from bouncing back and forth when they arrive inside a portal
+const PORTAL_COOLDOWN_MICROS: i64 = 3_000_000;
+
+// A box shaped volume that sends players entering it to a spawn point in another instance
+#[spacetimedb::table(name = portal, public)]
+#[derive(Clone, Debug)]
+pub struct Portal {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    // The instance the portal is placed in
+    #[index(btree)]
+    pub instance_id: u32,
+    pub position: DbVector3,
+    pub half_extents: DbVector3,
+
+    pub destination_instance_id: u32,
+    pub destination_spawn: DbVector3,
+}
+
+impl Portal {
+    // Sensor collider used to detect players entering the portal
+    pub fn collider(&self) -> Collider {
+        ColliderBuilder::cuboid(
+            self.half_extents.x,
+            self.half_extents.y,
+            self.half_extents.z,
+        )
+        .translation(self.position.into())
+        .sensor(true)
+        .collision_groups(*SCENE_COLLISION_GROUP)
+        .build()
+    }
+}
+
+// Last time a player went through a portal
+#[spacetimedb::table(name = portal_cooldown)]
+pub struct PortalCooldown {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub used_at: Timestamp,
+}
+
+fn is_on_cooldown(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db
+        .portal_cooldown()
+        .identity()
+        .find(identity)
+        .and_then(|cooldown| ctx.timestamp.duration_since(cooldown.used_at))
+        .is_some_and(|duration| (duration.as_micros() as i64) < PORTAL_COOLDOWN_MICROS)
+}
+
+fn start_cooldown(ctx: &ReducerContext, identity: Identity) {
+    let cooldown = PortalCooldown {
+        identity,
+        used_at: ctx.timestamp,
+    };
+    if ctx.db.portal_cooldown().identity().find(identity).is_some() {
+        ctx.db.portal_cooldown().identity().update(cooldown);
+    } else {
+        ctx.db.portal_cooldown().insert(cooldown);
+    }
+}
+
+// Add the sensors of every portal in the instance to its physics world
+pub fn load_portals(ctx: &ReducerContext, physics: &mut Physics, instance_id: u32) {
+    for portal in ctx.db.portal().instance_id().filter(instance_id) {
+        physics.add_portal(portal.id, portal.collider());
+    }
+}
+
+// Send players that entered a portal during the last step to its destination
+pub fn update_portals(ctx: &ReducerContext, worlds: &mut PhysicsWorlds) {
+    let entered: Vec<(Identity, u32)> = worlds
+        .values()
+        .flat_map(|physics| physics.players_in_portals())
+        .collect();
+
+    for (identity, portal_id) in entered {
+        if is_on_cooldown(ctx, identity) {
+            continue;
+        }
+        let Some(mut player) = ctx.db.player().identity().find(identity) else {
+            continue;
+        };
+        let Some(portal) = ctx.db.portal().id().find(portal_id) else {
+            continue;
+        };
+        let Some(destination) = ctx.db.instance().id().find(portal.destination_instance_id) else {
+            log::warn!(
+                "Portal {} leads to missing instance {}",
+                portal.id,
+                portal.destination_instance_id
+            );
+            continue;
+        };
+
+        // Players stay where they are while they can't join the destination, try again after the cooldown
+        start_cooldown(ctx, identity);
+        if destination.id != player.instance_id {
+            if let Err(error) = destination.ensure_can_join(ctx, identity) {
+                log::info!(
+                    "{} can't go through portal {}: {}",
+                    identity,
+                    portal.id,
+                    error
+                );
+                continue;
+            }
+        }
+
+        move_player(
+            ctx,
+            worlds,
+            &mut player,
+            &destination,
+            portal.destination_spawn,
+        );
+        log::info!(
+            "{} went through portal {} to instance {}",
+            identity,
+            portal.id,
+            destination.id
+        );
+        ctx.db.player().identity().update(player);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn create_portal(
+    ctx: &ReducerContext,
+    instance_id: u32,
+    position: DbVector3,
+    half_extents: DbVector3,
+    destination_instance_id: u32,
+    destination_spawn: DbVector3,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if !position.is_finite() || !destination_spawn.is_finite() {
+        return Err("Portal positions must be finite".to_string());
+    }
+    if ![half_extents.x, half_extents.y, half_extents.z]
+        .iter()
+        .all(|v| v.is_finite() && *v > 0.0)
+    {
+        return Err("Portal extents must be positive".to_string());
+    }
+    for id in [instance_id, destination_instance_id] {
+        if ctx.db.instance().id().find(id).is_none() {
+            return Err(format!("Instance {} not found", id));
+        }
+    }
+
+    let portal = ctx.db.portal().try_insert(Portal {
+        id: 0,
+        instance_id,
+        position,
+        half_extents,
+        destination_instance_id,
+        destination_spawn,
+    })?;
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    if let Some(physics) = worlds.get_mut(&instance_id) {
+        physics.add_portal(portal.id, portal.collider());
+    }
+
+    log::info!("{} created portal {}", ctx.sender, portal.id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_portal(ctx: &ReducerContext, portal_id: u32) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    let portal = ctx
+        .db
+        .portal()
+        .id()
+        .find(portal_id)
+        .ok_or("Portal not found")?;
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    if let Some(physics) = worlds.get_mut(&portal.instance_id) {
+        physics.remove_portal(portal.id);
+    }
+
+    ctx.db.portal().id().delete(portal.id);
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use crate::admin::ensure_admin;
+use crate::physics::{Physics, PHYSICS};
+use crate::player::{player, Player};
+use crate::world::instance::{instance as db_instance, instance_physics};
+use nalgebra::{Isometry3, Translation3, UnitQuaternion, Vector3};
+use shared::math::DbVector3;
+use spacetimedb::{Identity, ReducerContext, Table};
+
+// Maximum distance between a player and a seat to be able to sit on it
+const SIT_DISTANCE: f32 = 2.0;
+// How far in front of the seat a player is placed when standing up
+const STAND_OFFSET: f32 = 0.6;
+
+#[spacetimedb::table(name = seat, public)]
+#[derive(Clone, Debug)]
+pub struct Seat {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    // The instance the seat is placed in
+    #[index(btree)]
+    pub instance_id: u32,
+
+    pub position: DbVector3,
+    // Yaw the player faces while sitting
+    pub facing: f32,
+    pub occupant: Option<Identity>,
+}
+
+impl Seat {
+    pub fn transform(&self) -> Isometry3<f32> {
+        Isometry3::from_parts(
+            self.position.into(),
+            UnitQuaternion::from_axis_angle(&Vector3::y_axis(), self.facing),
+        )
+    }
+
+    // Where the player ends up when standing up, just in front of the seat
+    fn stand_transform(&self) -> Isometry3<f32> {
+        let transform = self.transform();
+        let forward = transform
+            .rotation
+            .transform_vector(&Vector3::new(0.0, 0.0, STAND_OFFSET));
+        Isometry3::from_parts(
+            Translation3::from(transform.translation.vector + forward),
+            transform.rotation,
+        )
+    }
+}
+
+// Free the player's seat and put them back in the physics world
+// Used by `stand`, and when the player leaves the world while seated
+pub fn release_seat(ctx: &ReducerContext, physics: &mut Physics, player: &mut Player) {
+    let Some(seat_id) = player.seat_id.take() else {
+        return;
+    };
+
+    // The seat can be gone already, the player then stands up where they are
+    let transform = match ctx.db.seat().id().find(seat_id) {
+        Some(mut seat) => {
+            let transform = seat.stand_transform();
+            seat.occupant = None;
+            ctx.db.seat().id().update(seat);
+            transform
+        }
+        None => player.body_position(),
+    };
+    physics.release_player(&player.identity, transform);
+    player.position = transform.translation.vector.into();
+    player.animation_state = Some("idle".to_string());
+}
+
+#[spacetimedb::reducer]
+pub fn create_seat(
+    ctx: &ReducerContext,
+    instance_id: u32,
+    position: DbVector3,
+    facing: f32,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    if ctx.db.instance().id().find(instance_id).is_none() {
+        return Err("Instance not found".to_string());
+    }
+    if !position.is_finite() || !facing.is_finite() {
+        return Err("Seat position and facing must be finite".to_string());
+    }
+
+    ctx.db.seat().try_insert(Seat {
+        id: 0,
+        instance_id,
+        position,
+        facing,
+        occupant: None,
+    })?;
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_seat(ctx: &ReducerContext, seat_id: u32) -> Result<(), String> {
+    ensure_admin(ctx)?;
+    let seat = ctx.db.seat().id().find(seat_id).ok_or("Seat not found")?;
+
+    // Make the occupant stand up first
+    if let Some(mut player) = seat
+        .occupant
+        .and_then(|identity| ctx.db.player().identity().find(identity))
+    {
+        let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+        let physics = instance_physics(ctx, &mut worlds, player.instance_id);
+        release_seat(ctx, physics, &mut player);
+        ctx.db.player().identity().update(player);
+    }
+
+    ctx.db.seat().id().delete(seat_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn sit(ctx: &ReducerContext, seat_id: u32) -> Result<(), String> {
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    if player.seat_id.is_some() {
+        return Err("Player is already seated".to_string());
+    }
+
+    let mut seat = ctx.db.seat().id().find(seat_id).ok_or("Seat not found")?;
+    if seat.instance_id != player.instance_id {
+        return Err("Seat is in another instance".to_string());
+    }
+    if seat.occupant.is_some() {
+        return Err("Seat is already occupied".to_string());
+    }
+
+    let distance = (Vector3::from(seat.position) - Vector3::from(player.position)).magnitude();
+    if distance > SIT_DISTANCE {
+        return Err("Seat is too far away".to_string());
+    }
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    instance_physics(ctx, &mut worlds, player.instance_id)
+        .pin_player(&player.identity, seat.transform());
+
+    seat.occupant = Some(player.identity);
+    player.seat_id = Some(seat.id);
+    player.position = seat.position;
+    player.set_look(seat.facing, 0.0, 0.0);
+    player.animation_state = Some("sitting".to_string());
+
+    ctx.db.seat().id().update(seat);
+    ctx.db.player().identity().update(player);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn stand(ctx: &ReducerContext) -> Result<(), String> {
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    if player.seat_id.is_none() {
+        return Err("Player is not seated".to_string());
+    }
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    let physics = instance_physics(ctx, &mut worlds, player.instance_id);
+    release_seat(ctx, physics, &mut player);
+    ctx.db.player().identity().update(player);
+    Ok(())
+}
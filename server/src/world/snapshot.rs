@@ -0,0 +1,157 @@
+use crate::admin::ensure_admin;
+use crate::physics::{Physics, PHYSICS};
+use crate::player::player;
+use crate::world::collider;
+use crate::world::instance::{instance, Instance};
+use crate::world::platform::platform;
+use crate::world::portal::portal;
+use spacetimedb::{ReducerContext, Table, Timestamp};
+use std::collections::HashSet;
+
+// Serialized physics world of an instance, restored the next time the instance is loaded
+#[spacetimedb::table(name = physics_snapshot)]
+#[derive(Clone, Debug)]
+pub struct PhysicsSnapshot {
+    #[primary_key]
+    pub instance_id: u32,
+
+    pub scene_version: u32,
+    pub created_at: Timestamp,
+    pub state: Vec<u8>,
+}
+
+// Whether the colliders, platforms and portals of a restored world still match the tables
+fn matches_scene(ctx: &ReducerContext, physics: &Physics, instance: &Instance) -> bool {
+    let colliders: HashSet<u32> = ctx
+        .db
+        .collider()
+        .scene_version()
+        .filter(instance.scene_version)
+        .map(|collider| collider.id)
+        .collect();
+    let platforms: HashSet<u32> = ctx
+        .db
+        .platform()
+        .iter()
+        .filter(|platform| colliders.contains(&platform.collider_id))
+        .map(|platform| platform.id)
+        .collect();
+    let portals: HashSet<u32> = ctx
+        .db
+        .portal()
+        .instance_id()
+        .filter(instance.id)
+        .map(|portal| portal.id)
+        .collect();
+
+    physics
+        .scene_colliders
+        .values()
+        .copied()
+        .collect::<HashSet<_>>()
+        == colliders
+        && physics.platforms.keys().copied().collect::<HashSet<_>>() == platforms
+        && physics.portals.values().copied().collect::<HashSet<_>>() == portals
+}
+
+// Restore the snapshot of an instance if there is one, it is only used once
+pub fn restore_physics(ctx: &ReducerContext, instance: &Instance) -> Option<Physics> {
+    let snapshot = ctx.db.physics_snapshot().instance_id().find(instance.id)?;
+    ctx.db.physics_snapshot().instance_id().delete(instance.id);
+
+    if snapshot.scene_version != instance.scene_version {
+        log::warn!(
+            "Discarding physics snapshot of instance {}, the scene version changed",
+            instance.id
+        );
+        return None;
+    }
+
+    let mut physics = match Physics::restore(&snapshot.state) {
+        Ok(physics) => physics,
+        Err(error) => {
+            log::error!(
+                "Discarding physics snapshot of instance {}: {}",
+                instance.id,
+                error
+            );
+            return None;
+        }
+    };
+
+    if !matches_scene(ctx, &physics, instance) {
+        log::warn!(
+            "Discarding physics snapshot of instance {}, the scene changed",
+            instance.id
+        );
+        return None;
+    }
+
+    // Players kept moving after the snapshot was taken, the player table is up to date
+    let players: Vec<_> = ctx.db.player().instance_id().filter(instance.id).collect();
+    let stale: Vec<_> = physics
+        .players
+        .keys()
+        .filter(|identity| !players.iter().any(|player| player.identity == **identity))
+        .copied()
+        .collect();
+    for identity in stale {
+        physics.remove_player(&identity);
+    }
+    for player in players {
+        if physics.players.contains_key(&player.identity) {
+            physics.set_player_position(&player.identity, player.body_position());
+            physics.set_player_movement_mode(
+                &player.identity,
+                player.movement_mode,
+                player.collides_with_players(ctx),
+            );
+        } else {
+            player.add_to_physics(ctx, &mut physics);
+        }
+    }
+
+    log::info!(
+        "Restored physics world of instance {} from {}",
+        instance.id,
+        snapshot.created_at
+    );
+    Some(physics)
+}
+
+#[spacetimedb::reducer]
+pub fn snapshot_physics(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_admin(ctx)?;
+
+    let worlds = PHYSICS.lock().expect("Failed to lock physics");
+    for (instance_id, physics) in worlds.iter() {
+        let Some(instance) = ctx.db.instance().id().find(*instance_id) else {
+            continue;
+        };
+
+        let snapshot = PhysicsSnapshot {
+            instance_id: instance.id,
+            scene_version: instance.scene_version,
+            created_at: ctx.timestamp,
+            state: physics.snapshot()?,
+        };
+        if ctx
+            .db
+            .physics_snapshot()
+            .instance_id()
+            .find(instance.id)
+            .is_some()
+        {
+            ctx.db.physics_snapshot().instance_id().update(snapshot);
+        } else {
+            ctx.db.physics_snapshot().insert(snapshot);
+        }
+    }
+
+    log::info!(
+        "{} took a snapshot of {} physics worlds",
+        ctx.sender,
+        worlds.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::admin::ensure_admin;
+use crate::physics::PHYSICS;
+use crate::world::collider;
+use crate::world::instance::scene_physics;
+use crate::world::platform::platform;
+use shared::volume::VolumeType;
+use spacetimedb::ReducerContext;
+
+// Turn an uploaded collider into a volume, or back into a solid collider
+#[spacetimedb::reducer]
+pub fn set_collider_volume(
+    ctx: &ReducerContext,
+    collider_id: u32,
+    volume: Option<VolumeType>,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+
+    let mut collider = ctx
+        .db
+        .collider()
+        .id()
+        .find(collider_id)
+        .ok_or("Collider not found")?;
+    if ctx.db.platform().collider_id().find(collider_id).is_some() {
+        return Err("Platforms can't be volumes".to_string());
+    }
+    collider.volume = volume;
+
+    let mut worlds = PHYSICS.lock().expect("Failed to lock physics");
+    for physics in scene_physics(ctx, &mut worlds, collider.scene_version) {
+        physics.remove_collider(collider.id);
+        collider.add_to_physics(physics);
+    }
+    ctx.db.collider().id().update(collider);
+
+    log::info!(
+        "{} set the volume of collider {} to {:?}",
+        ctx.sender,
+        collider_id,
+        volume
+    );
+    Ok(())
+}
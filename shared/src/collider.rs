@@ -0,0 +1,36 @@
+use crate::material::SurfaceMaterial;
+use rapier3d::prelude::*;
+
+// Build the collider of a scene mesh from its world space positions and triangle indices
+// Volumes become sensors, everything else a trimesh with the friction and restitution of its material
+// Trimeshes are oriented, so point queries can tell whether a point is enclosed by a closed mesh
+// Returns None when the mesh is invalid (e.g. no triangles or out of range indices)
+pub fn mesh_builder(
+    positions: Vec<Point<f32>>,
+    indices: Vec<[u32; 3]>,
+    material: &SurfaceMaterial,
+    volume: bool,
+) -> Option<ColliderBuilder> {
+    if volume {
+        return volume_builder(&positions);
+    }
+    ColliderBuilder::trimesh_with_flags(positions, indices, TriMeshFlags::ORIENTED)
+        .ok()
+        .map(|builder| material.apply(builder))
+}
+
+impl SurfaceMaterial {
+    // Apply the friction and restitution of the material, and tag the collider with its surface
+    pub fn apply(&self, builder: ColliderBuilder) -> ColliderBuilder {
+        builder
+            .friction(self.friction)
+            .restitution(self.restitution)
+            .user_data(self.surface.to_user_data())
+    }
+}
+
+// Build a sensor filling the convex hull of the positions
+// Trimeshes are hollow, so they can't tell whether something is inside of them
+pub fn volume_builder(positions: &[Point<f32>]) -> Option<ColliderBuilder> {
+    ColliderBuilder::convex_hull(positions).map(|builder| builder.sensor(true))
+}
@@ -0,0 +1,7 @@
+// Scene data and math types shared between the server module, the glTF uploader and the replay tool
+pub mod collider;
+pub mod material;
+pub mod math;
+pub mod mesh;
+pub mod scene;
+pub mod volume;
@@ -0,0 +1,145 @@
+use serde::Deserialize;
+use spacetimedb_lib::SpacetimeType;
+
+// Kind of surface a collider is made of, clients use it to pick footstep sounds
+#[derive(SpacetimeType, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[sats(crate = spacetimedb_lib)]
+pub enum SurfaceType {
+    #[default]
+    Default,
+    Grass,
+    Wood,
+    Stone,
+    Metal,
+    Sand,
+    Water,
+}
+
+impl SurfaceType {
+    pub const ALL: [SurfaceType; 7] = [
+        SurfaceType::Default,
+        SurfaceType::Grass,
+        SurfaceType::Wood,
+        SurfaceType::Stone,
+        SurfaceType::Metal,
+        SurfaceType::Sand,
+        SurfaceType::Water,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SurfaceType::Default => "default",
+            SurfaceType::Grass => "grass",
+            SurfaceType::Wood => "wood",
+            SurfaceType::Stone => "stone",
+            SurfaceType::Metal => "metal",
+            SurfaceType::Sand => "sand",
+            SurfaceType::Water => "water",
+        }
+    }
+
+    // Find the surface type mentioned in a name like "M_Grass_01"
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        Self::ALL[1..]
+            .iter()
+            .copied()
+            .find(|surface| name.contains(surface.name()))
+    }
+
+    // The surface is stored in the rapier collider's user data, so queries can read it back
+    pub fn to_user_data(self) -> u128 {
+        self as u128
+    }
+
+    pub fn from_user_data(user_data: u128) -> Self {
+        Self::ALL
+            .get(user_data as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+// Physical material of a collider
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+#[sats(crate = spacetimedb_lib)]
+pub struct SurfaceMaterial {
+    pub surface: SurfaceType,
+    pub friction: f32,
+    // Bounciness, between 0 and 1
+    pub restitution: f32,
+}
+
+impl Default for SurfaceMaterial {
+    fn default() -> Self {
+        Self::new(SurfaceType::Default)
+    }
+}
+
+// Material settings in the extras of a glTF material, e.g. `{ "surface": "wood", "friction": 0.4 }`
+#[derive(Deserialize)]
+struct MaterialExtras {
+    surface: Option<String>,
+    friction: Option<f32>,
+    restitution: Option<f32>,
+}
+
+impl SurfaceMaterial {
+    // Typical friction and restitution of the surface type
+    pub fn new(surface: SurfaceType) -> Self {
+        let (friction, restitution) = match surface {
+            SurfaceType::Default => (0.5, 0.0),
+            SurfaceType::Grass => (0.6, 0.0),
+            SurfaceType::Wood => (0.5, 0.1),
+            SurfaceType::Stone => (0.7, 0.05),
+            SurfaceType::Metal => (0.3, 0.1),
+            SurfaceType::Sand => (0.8, 0.0),
+            SurfaceType::Water => (0.1, 0.0),
+        };
+
+        Self {
+            surface,
+            friction,
+            restitution,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.friction.is_finite() || self.friction < 0.0 {
+            return Err(format!("Invalid friction {}", self.friction));
+        }
+        if !(0.0..=1.0).contains(&self.restitution) {
+            return Err(format!("Invalid restitution {}", self.restitution));
+        }
+        Ok(())
+    }
+
+    // Read the material from the extras of a glTF material, falling back to its name
+    pub fn from_gltf(material: &gltf::Material) -> Self {
+        let extras = material.extras().as_ref().and_then(|extras| {
+            serde_json::from_str::<MaterialExtras>(extras.get())
+                .inspect_err(|e| {
+                    log::warn!(
+                        "Invalid extras on material {:?}: {}",
+                        material.name().unwrap_or("unnamed"),
+                        e
+                    )
+                })
+                .ok()
+        });
+
+        let surface = extras
+            .as_ref()
+            .and_then(|extras| extras.surface.as_deref())
+            .or(material.name())
+            .and_then(SurfaceType::from_name)
+            .unwrap_or_default();
+
+        let mut result = Self::new(surface);
+        if let Some(extras) = extras {
+            result.friction = extras.friction.unwrap_or(result.friction);
+            result.restitution = extras.restitution.unwrap_or(result.restitution);
+        }
+        result
+    }
+}
@@ -0,0 +1,498 @@
+#[cfg(test)]
+mod tests;
+
+use nalgebra::{
+    Isometry3, Matrix4, Point3, Quaternion, Transform3, Translation3, UnitQuaternion, Vector3,
+    Vector4,
+};
+use rapier3d::parry::bounding_volume::Aabb;
+use spacetimedb_lib::SpacetimeType;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+#[sats(crate = spacetimedb_lib)]
+pub struct DbVector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl DbVector3 {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn zeros() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    pub fn ones() -> Self {
+        Self::new(1.0, 1.0, 1.0)
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        Vector3::from(*self).magnitude()
+    }
+
+    // Multiply each component with the matching component of `other`
+    pub fn component_mul(&self, other: &Self) -> Self {
+        Self::new(self.x * other.x, self.y * other.y, self.z * other.z)
+    }
+
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+    }
+}
+
+// Convert between DbVector3 and nalgebra::Vector3<f32>
+impl From<DbVector3> for Vector3<f32> {
+    fn from(vector3: DbVector3) -> Self {
+        Vector3::new(vector3.x, vector3.y, vector3.z)
+    }
+}
+
+// Convert between nalgebra::Vector3<f32> and DbVector3
+impl From<Vector3<f32>> for DbVector3 {
+    fn from(vector3: Vector3<f32>) -> Self {
+        Self {
+            x: vector3.x,
+            y: vector3.y,
+            z: vector3.z,
+        }
+    }
+}
+
+// Convert between DbVector3 and nalgebra::Point3<f32> (rapier3d::prelude::Point)
+impl From<DbVector3> for Point3<f32> {
+    fn from(vector3: DbVector3) -> Self {
+        Point3::new(vector3.x, vector3.y, vector3.z)
+    }
+}
+
+impl From<Point3<f32>> for DbVector3 {
+    fn from(point: Point3<f32>) -> Self {
+        Self::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<DbVector3> for Translation3<f32> {
+    fn from(vector3: DbVector3) -> Self {
+        Translation3::new(vector3.x, vector3.y, vector3.z)
+    }
+}
+
+impl From<[f32; 3]> for DbVector3 {
+    fn from(array: [f32; 3]) -> Self {
+        Self::new(array[0], array[1], array[2])
+    }
+}
+
+// Triangle indices are uploaded as a vector of three floats
+impl From<[u32; 3]> for DbVector3 {
+    fn from(indices: [u32; 3]) -> Self {
+        Self::new(indices[0] as f32, indices[1] as f32, indices[2] as f32)
+    }
+}
+
+impl From<DbVector3> for [u32; 3] {
+    fn from(indices: DbVector3) -> Self {
+        [indices.x as u32, indices.y as u32, indices.z as u32]
+    }
+}
+
+impl From<DbVector3> for [f32; 3] {
+    fn from(vector3: DbVector3) -> Self {
+        [vector3.x, vector3.y, vector3.z]
+    }
+}
+
+impl Add for DbVector3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl AddAssign for DbVector3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for DbVector3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl SubAssign for DbVector3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for DbVector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f32> for DbVector3 {
+    type Output = Self;
+
+    fn mul(self, scalar: f32) -> Self {
+        Self::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl Div<f32> for DbVector3 {
+    type Output = Self;
+
+    fn div(self, scalar: f32) -> Self {
+        Self::new(self.x / scalar, self.y / scalar, self.z / scalar)
+    }
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+#[sats(crate = spacetimedb_lib)]
+pub struct DbQuaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl DbQuaternion {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    // Rotation around the up axis
+    pub fn from_yaw(yaw: f32) -> Self {
+        UnitQuaternion::from_axis_angle(&Vector3::y_axis(), yaw).into()
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    pub fn rotate(&self, vector: DbVector3) -> DbVector3 {
+        UnitQuaternion::from(*self)
+            .transform_vector(&vector.into())
+            .into()
+    }
+
+    // q and -q describe the same rotation, so both are considered equal
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        let dot = self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w;
+        let sign = if dot < 0.0 { -1.0 } else { 1.0 };
+        (self.x - other.x * sign).abs() <= epsilon
+            && (self.y - other.y * sign).abs() <= epsilon
+            && (self.z - other.z * sign).abs() <= epsilon
+            && (self.w - other.w * sign).abs() <= epsilon
+    }
+}
+
+// Convert between DbQuaternion and nalgebra::UnitQuaternion<f32>
+// The quaternion is normalized, since rows can hold any four floats
+impl From<DbQuaternion> for UnitQuaternion<f32> {
+    fn from(quaternion: DbQuaternion) -> Self {
+        UnitQuaternion::from_quaternion(quaternion.into())
+    }
+}
+
+impl From<UnitQuaternion<f32>> for DbQuaternion {
+    fn from(quaternion: UnitQuaternion<f32>) -> Self {
+        quaternion.into_inner().into()
+    }
+}
+
+// Convert between DbQuaternion and nalgebra::Quaternion<f32>
+impl From<DbQuaternion> for Quaternion<f32> {
+    fn from(quaternion: DbQuaternion) -> Self {
+        Quaternion::new(quaternion.w, quaternion.x, quaternion.y, quaternion.z)
+    }
+}
+
+impl From<Quaternion<f32>> for DbQuaternion {
+    fn from(quaternion: Quaternion<f32>) -> Self {
+        Self {
+            x: quaternion.i,
+            y: quaternion.j,
+            z: quaternion.k,
+            w: quaternion.w,
+        }
+    }
+}
+
+// Combine two rotations, applying `other` first
+impl Mul for DbQuaternion {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        (UnitQuaternion::from(self) * UnitQuaternion::from(other)).into()
+    }
+}
+
+// Translation, rotation and (non-uniform) scale of an object, applied as scale -> rotate -> translate
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+#[sats(crate = spacetimedb_lib)]
+pub struct DbTransform {
+    pub translation: DbVector3,
+    pub rotation: DbQuaternion,
+    pub scale: DbVector3,
+}
+
+impl DbTransform {
+    pub fn new(translation: DbVector3, rotation: DbQuaternion, scale: DbVector3) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(
+            DbVector3::zeros(),
+            DbQuaternion::identity(),
+            DbVector3::ones(),
+        )
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.translation.is_finite() && self.rotation.is_finite() && self.scale.is_finite()
+    }
+
+    pub fn transform_point(&self, point: DbVector3) -> DbVector3 {
+        self.rotation.rotate(point.component_mul(&self.scale)) + self.translation
+    }
+
+    pub fn transform_vector(&self, vector: DbVector3) -> DbVector3 {
+        self.rotation.rotate(vector.component_mul(&self.scale))
+    }
+
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.translation.approx_eq(&other.translation, epsilon)
+            && self.rotation.approx_eq(&other.rotation, epsilon)
+            && self.scale.approx_eq(&other.scale, epsilon)
+    }
+}
+
+// Only translation and rotation carry over to an isometry, the scale is dropped
+impl From<DbTransform> for Isometry3<f32> {
+    fn from(transform: DbTransform) -> Self {
+        Isometry3::from_parts(transform.translation.into(), transform.rotation.into())
+    }
+}
+
+impl From<Isometry3<f32>> for DbTransform {
+    fn from(isometry: Isometry3<f32>) -> Self {
+        Self::new(
+            isometry.translation.vector.into(),
+            isometry.rotation.into(),
+            DbVector3::ones(),
+        )
+    }
+}
+
+impl From<DbTransform> for Matrix4<f32> {
+    fn from(transform: DbTransform) -> Self {
+        Isometry3::from(transform).to_homogeneous()
+            * Matrix4::new_nonuniform_scaling(&transform.scale.into())
+    }
+}
+
+impl From<DbTransform> for Transform3<f32> {
+    fn from(transform: DbTransform) -> Self {
+        Transform3::from_matrix_unchecked(transform.into())
+    }
+}
+
+// Apply `other` in the space of `self` (parent * child)
+// Exact for uniform scales, non-uniform scales combined with rotations can't be represented
+impl Mul for DbTransform {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.transform_point(other.translation),
+            self.rotation * other.rotation,
+            self.scale.component_mul(&other.scale),
+        )
+    }
+}
+
+// Axis aligned bounding box
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+#[sats(crate = spacetimedb_lib)]
+pub struct DbAabb {
+    pub min: DbVector3,
+    pub max: DbVector3,
+}
+
+impl DbAabb {
+    pub fn new(min: DbVector3, max: DbVector3) -> Self {
+        Self { min, max }
+    }
+
+    pub fn from_center(center: DbVector3, half_extents: DbVector3) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    pub fn center(&self) -> DbVector3 {
+        (self.min + self.max) / 2.0
+    }
+
+    pub fn half_extents(&self) -> DbVector3 {
+        (self.max - self.min) / 2.0
+    }
+
+    pub fn contains(&self, point: DbVector3) -> bool {
+        point.x >= self.min.x
+            && point.y >= self.min.y
+            && point.z >= self.min.z
+            && point.x <= self.max.x
+            && point.y <= self.max.y
+            && point.z <= self.max.z
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.min.y <= other.max.y
+            && self.min.z <= other.max.z
+            && other.min.x <= self.max.x
+            && other.min.y <= self.max.y
+            && other.min.z <= self.max.z
+    }
+
+    // Smallest box containing both boxes
+    pub fn merged(&self, other: &Self) -> Self {
+        Self::new(
+            DbVector3::new(
+                self.min.x.min(other.min.x),
+                self.min.y.min(other.min.y),
+                self.min.z.min(other.min.z),
+            ),
+            DbVector3::new(
+                self.max.x.max(other.max.x),
+                self.max.y.max(other.max.y),
+                self.max.z.max(other.max.z),
+            ),
+        )
+    }
+
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.min.approx_eq(&other.min, epsilon) && self.max.approx_eq(&other.max, epsilon)
+    }
+}
+
+// Convert between DbAabb and rapier3d::parry::bounding_volume::Aabb
+impl From<DbAabb> for Aabb {
+    fn from(aabb: DbAabb) -> Self {
+        Aabb::new(aabb.min.into(), aabb.max.into())
+    }
+}
+
+impl From<Aabb> for DbAabb {
+    fn from(aabb: Aabb) -> Self {
+        Self::new(aabb.mins.into(), aabb.maxs.into())
+    }
+}
+
+// Linear RGBA color with components between 0 and 1
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+#[sats(crate = spacetimedb_lib)]
+pub struct DbColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl DbColor {
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+        Self::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 1.0)
+    }
+
+    // Parse a "#RRGGBB" or "#RRGGBBAA" hex string
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#')?;
+        if !matches!(digits.len(), 6 | 8) || !digits.is_ascii() {
+            return None;
+        }
+
+        let channel = |i: usize| {
+            digits
+                .get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .map(|c| c as f32 / 255.0)
+        };
+        Some(Self::new(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+            if digits.len() == 8 { channel(6)? } else { 1.0 },
+        ))
+    }
+
+    // Format as "#RRGGBB", or "#RRGGBBAA" when the color isn't opaque
+    pub fn to_hex(&self) -> String {
+        let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let rgb = format!(
+            "#{:02X}{:02X}{:02X}",
+            channel(self.r),
+            channel(self.g),
+            channel(self.b)
+        );
+        if self.a < 1.0 {
+            format!("{}{:02X}", rgb, channel(self.a))
+        } else {
+            rgb
+        }
+    }
+
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        (self.r - other.r).abs() <= epsilon
+            && (self.g - other.g).abs() <= epsilon
+            && (self.b - other.b).abs() <= epsilon
+            && (self.a - other.a).abs() <= epsilon
+    }
+}
+
+impl From<DbColor> for Vector4<f32> {
+    fn from(color: DbColor) -> Self {
+        Vector4::new(color.r, color.g, color.b, color.a)
+    }
+}
+
+impl From<Vector4<f32>> for DbColor {
+    fn from(vector: Vector4<f32>) -> Self {
+        Self::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl From<[f32; 4]> for DbColor {
+    fn from(array: [f32; 4]) -> Self {
+        Self::new(array[0], array[1], array[2], array[3])
+    }
+}
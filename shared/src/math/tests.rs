@@ -0,0 +1,110 @@
+use super::*;
+use std::f32::consts::FRAC_PI_2;
+
+const EPSILON: f32 = 1e-5;
+
+fn transform(translation: [f32; 3], yaw: f32, scale: f32) -> DbTransform {
+    DbTransform::new(
+        translation.into(),
+        DbQuaternion::from_yaw(yaw),
+        DbVector3::new(scale, scale, scale),
+    )
+}
+
+#[test]
+fn transform_composition_applies_child_then_parent() {
+    let parent = transform([1.0, 2.0, 3.0], FRAC_PI_2, 2.0);
+    let child = transform([1.0, 0.0, 0.0], FRAC_PI_2, 0.5);
+    let point = DbVector3::new(0.0, 1.0, 2.0);
+
+    let composed = parent * child;
+    assert!(composed.transform_point(point).approx_eq(
+        &parent.transform_point(child.transform_point(point)),
+        EPSILON
+    ));
+    // A quarter turn around the up axis sends +x to -z, then it is scaled and moved
+    assert!(composed
+        .translation
+        .approx_eq(&DbVector3::new(1.0, 2.0, 1.0), EPSILON));
+    assert!(composed
+        .rotation
+        .approx_eq(&DbQuaternion::from_yaw(2.0 * FRAC_PI_2), EPSILON));
+    assert!(composed.scale.approx_eq(&DbVector3::ones(), EPSILON));
+}
+
+#[test]
+fn transform_identity_is_neutral() {
+    let transform = transform([4.0, -1.0, 0.5], 0.3, 1.5);
+    assert!((DbTransform::identity() * transform).approx_eq(&transform, EPSILON));
+    assert!((transform * DbTransform::identity()).approx_eq(&transform, EPSILON));
+}
+
+#[test]
+fn transform_matches_its_inverse_matrix() {
+    let transform = transform([4.0, -1.0, 0.5], 0.3, 1.5);
+    let point = DbVector3::new(-2.0, 3.0, 1.0);
+
+    let inverse = Transform3::from(transform)
+        .try_inverse()
+        .expect("Scaled transforms are invertible");
+    let moved = transform.transform_point(point);
+    let back: DbVector3 = inverse.transform_point(&moved.into()).into();
+    assert!(back.approx_eq(&point, EPSILON));
+
+    // Isometries drop the scale, so only rigid transforms round trip through them
+    let rigid = DbTransform::new(transform.translation, transform.rotation, DbVector3::ones());
+    let isometry = Isometry3::from(rigid);
+    assert!(DbTransform::from(isometry.inverse() * isometry)
+        .approx_eq(&DbTransform::identity(), EPSILON));
+}
+
+#[test]
+fn aabb_merge_contains_both_boxes() {
+    let a = DbAabb::new(DbVector3::new(0.0, 0.0, 0.0), DbVector3::new(1.0, 1.0, 1.0));
+    let b = DbAabb::from_center(
+        DbVector3::new(3.0, -1.0, 0.5),
+        DbVector3::new(0.5, 0.5, 0.5),
+    );
+
+    let merged = a.merged(&b);
+    assert!(merged.approx_eq(
+        &DbAabb::new(
+            DbVector3::new(0.0, -1.5, 0.0),
+            DbVector3::new(3.5, 1.0, 1.0)
+        ),
+        EPSILON
+    ));
+    for corner in [a.min, a.max, b.min, b.max] {
+        assert!(merged.contains(corner));
+    }
+    assert!(!a.intersects(&b));
+    assert!(merged.intersects(&a) && merged.intersects(&b));
+}
+
+#[test]
+fn aabb_contains_its_boundary_only() {
+    let aabb = DbAabb::from_center(DbVector3::zeros(), DbVector3::ones());
+    assert!(aabb.contains(DbVector3::zeros()));
+    assert!(aabb.contains(DbVector3::new(1.0, -1.0, 1.0)));
+    assert!(!aabb.contains(DbVector3::new(1.001, 0.0, 0.0)));
+    assert!(!aabb.contains(DbVector3::new(0.0, 0.0, -1.001)));
+}
+
+#[test]
+fn approx_eq_respects_epsilon() {
+    let vector = DbVector3::new(1.0, 2.0, 3.0);
+    assert!(vector.approx_eq(&DbVector3::new(1.05, 1.95, 3.0), 0.1));
+    assert!(!vector.approx_eq(&DbVector3::new(1.2, 2.0, 3.0), 0.1));
+
+    let color = DbColor::new(0.5, 0.5, 0.5, 1.0);
+    assert!(color.approx_eq(&DbColor::from_rgb8(128, 127, 128), 0.01));
+    assert!(!color.approx_eq(&DbColor::new(0.5, 0.5, 0.5, 0.5), 0.01));
+}
+
+#[test]
+fn quaternion_approx_eq_ignores_sign() {
+    let rotation = DbQuaternion::from_yaw(0.7);
+    let negated = DbQuaternion::new(-rotation.x, -rotation.y, -rotation.z, -rotation.w);
+    assert!(rotation.approx_eq(&negated, EPSILON));
+    assert!(!rotation.approx_eq(&DbQuaternion::from_yaw(0.8), EPSILON));
+}
@@ -0,0 +1,30 @@
+use crate::material::SurfaceMaterial;
+use crate::volume::VolumeType;
+use nalgebra::Vector3;
+use rapier3d::prelude::Point;
+
+// Triangle mesh of a single scene node, in world space
+#[derive(Clone, Debug)]
+pub struct MeshData {
+    pub name: String,
+    pub positions: Vec<Point<f32>>,
+    pub indices: Vec<[u32; 3]>,
+    pub material: SurfaceMaterial,
+    // Set when the mesh marks a volume instead of a solid surface
+    pub volume: Option<VolumeType>,
+}
+
+// Offset of an animated node from its rest position at the given time
+#[derive(Clone, Copy, Debug)]
+pub struct Keyframe {
+    // Seconds since the start of the animation
+    pub time: f32,
+    pub translation: Vector3<f32>,
+}
+
+// Translation animation of a single scene node
+#[derive(Clone, Debug)]
+pub struct AnimationData {
+    pub name: String,
+    pub keyframes: Vec<Keyframe>,
+}
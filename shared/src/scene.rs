@@ -0,0 +1,245 @@
+use crate::material::SurfaceMaterial;
+use crate::mesh::{AnimationData, Keyframe, MeshData};
+use crate::volume::VolumeType;
+use nalgebra::{Matrix4, Transform3, Vector3};
+use rapier3d::prelude::Point;
+use std::collections::HashMap;
+
+type Primitive = (Vec<Point<f32>>, Vec<[u32; 3]>);
+
+// Read the primitive data from the gltf file and return the positions and indices.
+fn read_primitive(
+    vertex_offset: u32,
+    primitive: &gltf::mesh::Primitive,
+    buffers: &[gltf::buffer::Data],
+) -> Option<Primitive> {
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let positions: Vec<Point<f32>> = if let Some(ps) = reader.read_positions() {
+        ps.map(|p| Point::new(p[0], p[1], p[2])).collect()
+    } else {
+        return None;
+    };
+
+    let mut indices = Vec::new();
+    match primitive.mode() {
+        gltf::mesh::Mode::Triangles => {
+            if let Some(is) = reader.read_indices() {
+                let mut iter = is.into_u32();
+                while let (Some(a), Some(b), Some(c)) = (iter.next(), iter.next(), iter.next()) {
+                    indices.push([a + vertex_offset, b + vertex_offset, c + vertex_offset]);
+                }
+            } else {
+                for i in 0..positions.len() as u32 / 3 {
+                    let base = i * 3;
+                    indices.push([
+                        base + vertex_offset,
+                        base + 1 + vertex_offset,
+                        base + 2 + vertex_offset,
+                    ]);
+                }
+            }
+        }
+        gltf::mesh::Mode::TriangleStrip => {
+            if let Some(is) = reader.read_indices() {
+                let mut iter = is.into_u32();
+                while let (Some(a), Some(b), Some(c)) = (iter.next(), iter.next(), iter.next()) {
+                    indices.push([a + vertex_offset, b + vertex_offset, c + vertex_offset]);
+                }
+            } else {
+                for i in 0..positions.len() as u32 - 2 {
+                    if i % 2 == 0 {
+                        indices.push([
+                            i + vertex_offset,
+                            i + 1 + vertex_offset,
+                            i + 2 + vertex_offset,
+                        ]);
+                    } else {
+                        indices.push([
+                            i + vertex_offset,
+                            i + 2 + vertex_offset,
+                            i + 1 + vertex_offset,
+                        ]);
+                    }
+                }
+            }
+        }
+        gltf::mesh::Mode::TriangleFan => {
+            if let Some(is) = reader.read_indices() {
+                let mut iter = is.into_u32();
+                while let (Some(a), Some(b), Some(c)) = (iter.next(), iter.next(), iter.next()) {
+                    indices.push([a + vertex_offset, b + vertex_offset, c + vertex_offset]);
+                }
+            } else {
+                let center = vertex_offset;
+                for i in 1..positions.len() as u32 - 1 {
+                    indices.push([center, i + vertex_offset, i + 1 + vertex_offset]);
+                }
+            }
+        }
+        _ => {
+            return None;
+        }
+    }
+
+    Some((positions, indices))
+}
+
+// Read all primitives of a gltf mesh into a single set of transformed positions and indices.
+fn points_from_mesh(
+    mesh: &gltf::mesh::Mesh,
+    buffers: &[gltf::buffer::Data],
+    transform: &Transform3<f32>,
+) -> Primitive {
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+    let mut vertex_offset = 0;
+
+    for primitive in mesh.primitives() {
+        if let Some((ps, is)) = read_primitive(vertex_offset, &primitive, buffers) {
+            let transformed_points = ps
+                .into_iter()
+                .map(|p| transform.transform_point(&p))
+                .collect::<Vec<_>>();
+
+            positions.extend(transformed_points);
+            indices.extend(is);
+            vertex_offset = positions.len() as u32;
+        }
+    }
+
+    (positions, indices)
+}
+
+fn place_colliders(
+    node: &gltf::scene::Node,
+    buffers: &[gltf::buffer::Data],
+    transform: &Transform3<f32>,
+) -> Vec<MeshData> {
+    let mut meshes = Vec::new();
+
+    let node_matrix = Matrix4::from(node.transform().matrix());
+    let combined_transform = transform * Transform3::from_matrix_unchecked(node_matrix);
+
+    for child in node.children() {
+        meshes.extend(place_colliders(&child, buffers, &combined_transform));
+    }
+
+    if let Some(mesh) = node.mesh() {
+        let (positions, indices) = points_from_mesh(&mesh, buffers, &combined_transform);
+        // Primitives are merged into a single collider, which uses the material of the first one
+        let material = mesh
+            .primitives()
+            .next()
+            .map(|primitive| SurfaceMaterial::from_gltf(&primitive.material()))
+            .unwrap_or_default();
+        let name = node.name().unwrap_or("unnamed").to_string();
+        meshes.push(MeshData {
+            volume: VolumeType::from_name(&name),
+            name,
+            positions,
+            indices,
+            material,
+        });
+    }
+
+    log::info!(
+        "Node: {:?}, meshes: {:?}",
+        node.name().unwrap_or("unnamed"),
+        meshes.len()
+    );
+
+    meshes
+}
+
+// Read the mesh of every node in the scene, in world space scaled by `scale`
+pub fn load_scene_mesh(path: &str, scale: f32) -> Vec<MeshData> {
+    let Ok((document, buffers, _)) = gltf::import(path) else {
+        log::error!("Failed to load scene from {}", path);
+        return Vec::new();
+    };
+
+    let scale = Transform3::from_matrix_unchecked(Matrix4::new_scaling(scale));
+    let mut meshes = Vec::new();
+    for scene in document.scenes() {
+        for node in scene.nodes() {
+            meshes.extend(place_colliders(&node, &buffers, &scale));
+        }
+    }
+
+    meshes
+}
+
+// Store the combined transform of every node's parent, keyed by node index.
+fn collect_parent_transforms(
+    node: &gltf::scene::Node,
+    transform: &Transform3<f32>,
+    parent_transforms: &mut HashMap<usize, Transform3<f32>>,
+) {
+    parent_transforms.insert(node.index(), *transform);
+
+    let node_matrix = Matrix4::from(node.transform().matrix());
+    let combined_transform = transform * Transform3::from_matrix_unchecked(node_matrix);
+    for child in node.children() {
+        collect_parent_transforms(&child, &combined_transform, parent_transforms);
+    }
+}
+
+// Read the translation animation channels of the scene, used to create moving platforms.
+// Keyframe translations are offsets from the node's rest position, in world space scaled by `scale`.
+pub fn load_scene_animations(path: &str, scale: f32) -> Vec<AnimationData> {
+    let Ok((document, buffers, _)) = gltf::import(path) else {
+        log::error!("Failed to load scene from {}", path);
+        return Vec::new();
+    };
+
+    let scale = Transform3::from_matrix_unchecked(Matrix4::new_scaling(scale));
+    let mut parent_transforms = HashMap::new();
+    for scene in document.scenes() {
+        for node in scene.nodes() {
+            collect_parent_transforms(&node, &scale, &mut parent_transforms);
+        }
+    }
+
+    let mut animations = Vec::new();
+    for animation in document.animations() {
+        for channel in animation.channels() {
+            let target = channel.target();
+            if target.property() != gltf::animation::Property::Translation {
+                continue;
+            }
+
+            let reader = channel.reader(|buffer| Some(&buffers[buffer.index()]));
+            let (Some(times), Some(gltf::animation::util::ReadOutputs::Translations(translations))) =
+                (reader.read_inputs(), reader.read_outputs())
+            else {
+                continue;
+            };
+
+            let node = target.node();
+            let rest_translation = Vector3::from(node.transform().decomposed().0);
+            let parent_transform = parent_transforms.get(&node.index()).unwrap_or(&scale);
+
+            let keyframes = times
+                .zip(translations)
+                .map(|(time, translation)| Keyframe {
+                    time,
+                    translation: parent_transform
+                        .transform_vector(&(Vector3::from(translation) - rest_translation)),
+                })
+                .collect::<Vec<_>>();
+
+            log::info!(
+                "Animation: {:?}, keyframes: {:?}",
+                node.name().unwrap_or("unnamed"),
+                keyframes.len()
+            );
+
+            animations.push(AnimationData {
+                name: node.name().unwrap_or("unnamed").to_string(),
+                keyframes,
+            });
+        }
+    }
+
+    animations
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests;
+
+use serde::{Deserialize, Serialize};
+use spacetimedb_lib::SpacetimeType;
+
+// Special region of the scene, players can move through it instead of standing on it
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[sats(crate = spacetimedb_lib)]
+pub enum VolumeType {
+    Water,
+    // Ladders and other surfaces players can climb
+    Climbable,
+}
+
+impl VolumeType {
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "water" => Some(VolumeType::Water),
+            "climbable" | "ladder" => Some(VolumeType::Climbable),
+            _ => None,
+        }
+    }
+
+    // Find the volume type tagged at the start or end of a node name, like "Water_Pond" or "Tree_Ladder.001"
+    // Tags have to be a whole word, so names like "Waterfall_Rock" stay solid
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        // Blender numbers duplicated nodes with a ".001" suffix
+        let name = match name.rsplit_once('.') {
+            Some((base, suffix)) if suffix.chars().all(|c| c.is_ascii_digit()) => base,
+            _ => &name,
+        };
+
+        let mut words = name
+            .split(['_', '-', '.', ' '])
+            .filter(|word| !word.is_empty());
+        let first = words.next()?;
+        let last = words.next_back();
+        Self::from_tag(first).or_else(|| last.and_then(Self::from_tag))
+    }
+}
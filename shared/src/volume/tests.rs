@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn tags_at_either_end_of_the_name() {
+    assert_eq!(VolumeType::from_name("Water"), Some(VolumeType::Water));
+    assert_eq!(VolumeType::from_name("water_pond"), Some(VolumeType::Water));
+    assert_eq!(VolumeType::from_name("Pond_Water"), Some(VolumeType::Water));
+    assert_eq!(
+        VolumeType::from_name("Tree_Ladder.001"),
+        Some(VolumeType::Climbable)
+    );
+    assert_eq!(
+        VolumeType::from_name("climbable-wall"),
+        Some(VolumeType::Climbable)
+    );
+}
+
+#[test]
+fn tags_must_be_whole_words_at_the_ends() {
+    assert_eq!(VolumeType::from_name("waterfall_rock"), None);
+    assert_eq!(VolumeType::from_name("Rock_Waterfall"), None);
+    assert_eq!(VolumeType::from_name("rock_water_edge"), None);
+    assert_eq!(VolumeType::from_name("Ladders"), None);
+    assert_eq!(VolumeType::from_name(""), None);
+}